@@ -1,5 +1,6 @@
-use crate::engine_process::EngineManager;
-use crate::ipc_bridge::IpcBridge;
+use crate::engine_process::{EngineManager, LaunchConfig, RestartPolicy};
+use crate::ipc_bridge::{ConnectionState, IpcBridge};
+use crate::terminal::{self, Shell, TerminalLauncher};
 use serde::{Deserialize, Serialize};
 use serde_json::{json, Value};
 use std::sync::Arc;
@@ -7,9 +8,10 @@ use std::process::Stdio;
 use std::path::PathBuf;
 use std::collections::HashMap;
 use tokio::process::Command;
-use tauri::{AppHandle, Emitter, Manager, State, WebviewUrl, WebviewWindowBuilder};
+use tauri::{AppHandle, Emitter, Manager, State, WebviewUrl, WebviewWindowBuilder, Window};
 use notify_debouncer_mini::{new_debouncer, notify::RecursiveMode};
 use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::time::Duration;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -30,13 +32,185 @@ pub struct CameraState {
     pub pitch: f32,
 }
 
+/// One engine instance in the pool: its process manager and the IPC bridge
+/// connected to the endpoint it resolved at launch. Paired together because each
+/// instance needs its own bridge - they can't share one global connection
+/// once more than one engine process may be running at a time.
+#[derive(Clone)]
+pub struct EngineInstance {
+    pub manager: Arc<EngineManager>,
+    pub ipc: Arc<IpcBridge>,
+}
+
+/// Summary of a pooled engine instance, returned by `list_engine_instances`.
+/// Each instance doubles as a "session" in the sense that it's an
+/// independently addressable engine process + bridge pair; `instance_id` is
+/// that session's id, supplied by the caller when it's created.
+#[derive(Debug, Clone, Serialize)]
+pub struct EngineInstanceInfo {
+    pub instance_id: String,
+    pub running: bool,
+    pub connection_state: ConnectionState,
+    /// The Tauri window that created this instance, if known - used to scope
+    /// cleanup to that window's instances instead of every pooled engine.
+    pub window_label: Option<String>,
+}
+
+/// Pool of engine instances keyed by a caller-chosen id, so more than one
+/// engine/viewport can run side by side (e.g. comparing two scenes, or one
+/// per floating window).
+pub struct EnginePool {
+    app: AppHandle,
+    instances: Mutex<HashMap<String, EngineInstance>>,
+    /// Which window created each instance, keyed by `instance_id`, so
+    /// `stop_window` can tear down only the instances a closed window owns
+    /// rather than every engine in the pool.
+    owners: Mutex<HashMap<String, String>>,
+}
+
+impl EnginePool {
+    pub fn new(app: AppHandle) -> Self {
+        Self {
+            app,
+            instances: Mutex::new(HashMap::new()),
+            owners: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Get the instance for `instance_id`, creating one - with its own
+    /// namespaced IPC transport - on first use.
+    fn get_or_create(&self, instance_id: &str) -> Result<EngineInstance, String> {
+        let mut instances = self.instances.lock().map_err(|e| e.to_string())?;
+
+        if let Some(existing) = instances.get(instance_id) {
+            return Ok(existing.clone());
+        }
+
+        let manager = EngineManager::with_config(self.app.clone(), LaunchConfig::new());
+        let ipc = Arc::new(IpcBridge::new());
+
+        // A supervised auto-restart relaunches the engine on a freshly
+        // namespaced socket path/pipe name with nobody calling
+        // `start_engine*` to point the bridge at it - re-point it here
+        // instead. `establish()`/`reconnect()` read the endpoint fresh on
+        // every attempt, so updating it is enough to let the bridge's own
+        // ongoing reconnect loop pick up the relaunched engine.
+        let ipc_for_restart = ipc.clone();
+        manager.set_restart_hook(move |endpoint| {
+            let ipc = ipc_for_restart.clone();
+            tauri::async_runtime::spawn(async move {
+                ipc.set_endpoint(endpoint).await;
+            });
+        });
+
+        let instance = EngineInstance { manager, ipc };
+        instances.insert(instance_id.to_string(), instance.clone());
+        Ok(instance)
+    }
+
+    fn get(&self, instance_id: &str) -> Result<EngineInstance, String> {
+        self.instances
+            .lock()
+            .map_err(|e| e.to_string())?
+            .get(instance_id)
+            .cloned()
+            .ok_or_else(|| format!("No engine instance '{}'", instance_id))
+    }
+
+    fn remove(&self, instance_id: &str) -> Option<EngineInstance> {
+        self.owners.lock().ok()?.remove(instance_id);
+        self.instances.lock().ok()?.remove(instance_id)
+    }
+
+    /// Record that `window_label` is the window responsible for
+    /// `instance_id`, called once after a `start_engine*` command creates or
+    /// reuses it.
+    fn set_owner_window(&self, instance_id: &str, window_label: &str) {
+        if let Ok(mut owners) = self.owners.lock() {
+            owners.insert(instance_id.to_string(), window_label.to_string());
+        }
+    }
+
+    /// Every pooled instance, e.g. to broadcast an IPC command to all of them.
+    fn all(&self) -> Vec<EngineInstance> {
+        self.instances
+            .lock()
+            .map(|instances| instances.values().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    async fn list(&self) -> Vec<EngineInstanceInfo> {
+        let (instance_ids, owners): (Vec<(String, EngineInstance)>, HashMap<String, String>) = {
+            let instances = self.instances.lock().ok();
+            let owners = self.owners.lock().ok();
+            match (instances, owners) {
+                (Some(instances), Some(owners)) => (
+                    instances.iter().map(|(id, inst)| (id.clone(), inst.clone())).collect(),
+                    owners.clone(),
+                ),
+                _ => return Vec::new(),
+            }
+        };
+
+        let mut info = Vec::with_capacity(instance_ids.len());
+        for (instance_id, instance) in instance_ids {
+            info.push(EngineInstanceInfo {
+                running: instance.manager.is_running(),
+                connection_state: instance.ipc.connection_state().await,
+                window_label: owners.get(&instance_id).cloned(),
+                instance_id,
+            });
+        }
+        info
+    }
+
+    /// Force-stop every instance owned by `window_label`, e.g. when that
+    /// window is destroyed - other windows' engines are left running.
+    pub fn stop_window(&self, window_label: &str) {
+        let owned: Vec<String> = self
+            .owners
+            .lock()
+            .map(|owners| {
+                owners
+                    .iter()
+                    .filter(|(_, owner)| owner.as_str() == window_label)
+                    .map(|(instance_id, _)| instance_id.clone())
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        for instance_id in owned {
+            if let Some(instance) = self.instances.lock().ok().and_then(|mut instances| instances.remove(&instance_id)) {
+                let _ = instance.manager.stop_with_force(true);
+
+                // Disconnect the bridge too, not just the process - otherwise
+                // its background read task sees the socket drop and enters
+                // `reconnect()`'s indefinite backoff loop against an
+                // endpoint nothing will ever listen on again, leaking both
+                // the reconnect task and the bridge it holds an `Arc` to.
+                let ipc = instance.ipc.clone();
+                tauri::async_runtime::spawn(async move {
+                    ipc.disconnect().await;
+                });
+            }
+            if let Ok(mut owners) = self.owners.lock() {
+                owners.remove(&instance_id);
+            }
+        }
+    }
+}
+
 #[tauri::command]
 pub async fn start_engine(
-    engine: State<'_, Arc<EngineManager>>,
-    ipc: State<'_, IpcBridge>,
+    window: Window,
+    pool: State<'_, EnginePool>,
+    instance_id: String,
 ) -> Result<(), String> {
+    let instance = pool.get_or_create(&instance_id)?;
+    pool.set_owner_window(&instance_id, window.label());
+
     // Start the engine process - returns false if skipped (already running/starting)
-    let actually_started = engine.start()?;
+    let actually_started = instance.manager.start()?;
     if !actually_started {
         return Ok(());  // Engine was already running/starting, skip the rest
     }
@@ -45,41 +219,71 @@ pub async fn start_engine(
     tokio::time::sleep(tokio::time::Duration::from_secs(2)).await;
 
     // Clear the starting flag now that initialization is complete
-    engine.finish_starting();
+    instance.manager.finish_starting();
 
-    // Connect to the engine's WebSocket server
-    ipc.connect().await?;
+    // Connect to the engine's IPC server, at whatever endpoint it resolved
+    if let Some(endpoint) = instance.manager.ipc_endpoint() {
+        instance.ipc.set_endpoint(endpoint).await;
+    }
+    instance.ipc.connect().await?;
 
     Ok(())
 }
 
+/// List the pooled engine instances, whether each is running, and its IPC
+/// connection state - the live view of every session the app currently has
+/// open, one per engine process.
+#[tauri::command]
+pub async fn list_engine_instances(
+    pool: State<'_, EnginePool>,
+) -> Result<Vec<EngineInstanceInfo>, String> {
+    Ok(pool.list().await)
+}
+
 #[tauri::command]
 pub async fn stop_engine(
-    engine: State<'_, Arc<EngineManager>>,
-    ipc: State<'_, IpcBridge>,
+    pool: State<'_, EnginePool>,
+    instance_id: String,
     force: Option<bool>,
+    graceful_timeout_ms: Option<u64>,
 ) -> Result<(), String> {
     let is_force = force.unwrap_or(false);
 
+    let instance = match pool.get(&instance_id) {
+        Ok(instance) => instance,
+        Err(_) => return Ok(()), // nothing to stop
+    };
+
     // Only disconnect and stop if force=true (window closing)
     // This prevents React StrictMode cleanup from killing the engine
     if is_force {
         // Disconnect from WebSocket first (ignore errors - may already be disconnected)
-        ipc.disconnect().await;
-        // Force stop the engine process
-        let _ = engine.stop_with_force(true);
+        instance.ipc.disconnect().await;
+
+        if let Some(timeout_ms) = graceful_timeout_ms {
+            // Give the engine a chance to shut down cleanly before killing it
+            let _ = instance.manager.stop_graceful(std::time::Duration::from_millis(timeout_ms));
+        } else {
+            let _ = instance.manager.stop_with_force(true);
+        }
+
+        pool.remove(&instance_id);
     } else {
         // Non-forced stop - just try to stop if not starting
         // Don't disconnect IPC (React StrictMode will remount)
-        let _ = engine.stop_with_force(false);
+        let _ = instance.manager.stop_with_force(false);
     }
 
     Ok(())
 }
 
 #[tauri::command]
-pub async fn get_stats(ipc: State<'_, IpcBridge>) -> Result<EngineStats, String> {
-    let response = ipc.send_command("stats.get", json!({})).await?;
+pub async fn get_stats(
+    pool: State<'_, EnginePool>,
+    instance_id: String,
+) -> Result<EngineStats, String> {
+    let instance = pool.get(&instance_id)?;
+    let response = instance.ipc.send_command("stats.get", json!({})).await?;
 
     // Parse the response
     let fps = response["fps"].as_f64().unwrap_or(0.0) as f32;
@@ -96,8 +300,12 @@ pub async fn get_stats(ipc: State<'_, IpcBridge>) -> Result<EngineStats, String>
 }
 
 #[tauri::command]
-pub async fn get_camera(ipc: State<'_, IpcBridge>) -> Result<CameraState, String> {
-    let response = ipc.send_command("camera.get", json!({})).await?;
+pub async fn get_camera(
+    pool: State<'_, EnginePool>,
+    instance_id: String,
+) -> Result<CameraState, String> {
+    let instance = pool.get(&instance_id)?;
+    let response = instance.ipc.send_command("camera.get", json!({})).await?;
 
     let position = response["position"]
         .as_array()
@@ -122,40 +330,108 @@ pub async fn get_camera(ipc: State<'_, IpcBridge>) -> Result<CameraState, String
 
 #[tauri::command]
 pub async fn set_camera(
-    ipc: State<'_, IpcBridge>,
+    pool: State<'_, EnginePool>,
+    instance_id: String,
     camera: CameraState,
 ) -> Result<(), String> {
+    let instance = pool.get(&instance_id)?;
     let params = json!({
         "position": camera.position,
         "yaw": camera.yaw,
         "pitch": camera.pitch,
     });
 
-    ipc.send_command("camera.set", params).await?;
+    instance.ipc.send_command("camera.set", params).await?;
     Ok(())
 }
 
 #[tauri::command]
 pub async fn send_command(
-    ipc: State<'_, IpcBridge>,
+    pool: State<'_, EnginePool>,
+    instance_id: String,
     method: String,
     params: Value,
 ) -> Result<Value, String> {
-    ipc.send_command(&method, params).await
+    let instance = pool.get(&instance_id)?;
+    instance.ipc.send_command(&method, params).await
+}
+
+/// Subscribe to a live event stream from an engine instance (e.g.
+/// `"stats.subscribe"`), forwarding every event the engine pushes to the
+/// frontend as `event_name`. If `tab_id` names an open floating panel it's
+/// routed there with `emit_to`; otherwise it's broadcast to every window
+/// with `emit`, same as the rest of the app's events. Returns a subscription
+/// handle the caller should hand back to `unsubscribe_engine_events` once
+/// it's done listening (e.g. when the panel closes).
+#[tauri::command]
+pub async fn subscribe_engine_events(
+    app: AppHandle,
+    pool: State<'_, EnginePool>,
+    floating_state: State<'_, Mutex<FloatingWindowsState>>,
+    instance_id: String,
+    method: String,
+    params: Value,
+    event_name: String,
+    tab_id: Option<String>,
+) -> Result<String, String> {
+    let instance = pool.get(&instance_id)?;
+    let (handle, mut events) = instance.ipc.subscribe(&method, params).await?;
+
+    let target_window = match &tab_id {
+        Some(tab_id) => floating_state
+            .lock()
+            .map_err(|e| e.to_string())?
+            .windows
+            .get(tab_id)
+            .cloned(),
+        None => None,
+    };
+
+    tokio::spawn(async move {
+        while let Some(response) = events.recv().await {
+            let payload = response.data.unwrap_or(json!({}));
+            let result = match &target_window {
+                Some(window_label) => app.emit_to(window_label, &event_name, payload),
+                None => app.emit(&event_name, payload),
+            };
+            if let Err(e) = result {
+                println!("[EngineEvents] Failed to emit '{}': {}", event_name, e);
+            }
+        }
+    });
+
+    Ok(handle)
+}
+
+/// Stop forwarding events for a subscription created by
+/// `subscribe_engine_events`.
+#[tauri::command]
+pub async fn unsubscribe_engine_events(
+    pool: State<'_, EnginePool>,
+    instance_id: String,
+    subscription_id: String,
+) -> Result<(), String> {
+    let instance = pool.get(&instance_id)?;
+    instance.ipc.unsubscribe(&subscription_id).await;
+    Ok(())
 }
 
-/// Start the engine embedded in a parent window using SetParent
+/// Start an engine instance embedded in a parent window using SetParent
 #[tauri::command]
 pub async fn start_engine_with_parent(
-    engine: State<'_, Arc<EngineManager>>,
-    ipc: State<'_, IpcBridge>,
+    window: Window,
+    pool: State<'_, EnginePool>,
+    instance_id: String,
     parent_hwnd: u64,
 ) -> Result<(), String> {
+    let instance = pool.get_or_create(&instance_id)?;
+    pool.set_owner_window(&instance_id, window.label());
+
     // Set the parent HWND for true embedding
-    engine.set_parent_hwnd(parent_hwnd);
+    instance.manager.set_parent_hwnd(parent_hwnd);
 
     // Start the engine - returns false if skipped (already running/starting)
-    let actually_started = engine.start()?;
+    let actually_started = instance.manager.start()?;
     if !actually_started {
         return Ok(());  // Engine was already running/starting, skip the rest
     }
@@ -164,22 +440,29 @@ pub async fn start_engine_with_parent(
     tokio::time::sleep(tokio::time::Duration::from_millis(800)).await;
 
     // Clear the starting flag now that initialization is complete
-    engine.finish_starting();
+    instance.manager.finish_starting();
 
-    // Connect to the engine's WebSocket server
-    ipc.connect().await?;
+    // Connect to the engine's IPC server
+    if let Some(endpoint) = instance.manager.ipc_endpoint() {
+        instance.ipc.set_endpoint(endpoint).await;
+    }
+    instance.ipc.connect().await?;
 
     Ok(())
 }
 
-/// Start the engine in overlay mode (positioned over viewport) - fallback
+/// Start an engine instance in overlay mode (positioned over viewport) - fallback
 #[tauri::command]
 pub async fn start_engine_embedded(
-    engine: State<'_, Arc<EngineManager>>,
-    ipc: State<'_, IpcBridge>,
+    window: Window,
+    pool: State<'_, EnginePool>,
+    instance_id: String,
 ) -> Result<(), String> {
+    let instance = pool.get_or_create(&instance_id)?;
+    pool.set_owner_window(&instance_id, window.label());
+
     // Start the engine - returns false if skipped (already running/starting)
-    let actually_started = engine.start()?;
+    let actually_started = instance.manager.start()?;
 
     if actually_started {
         // We actually started the engine, wait for it to initialize
@@ -187,44 +470,51 @@ pub async fn start_engine_embedded(
         tokio::time::sleep(tokio::time::Duration::from_millis(1000)).await;
 
         // Clear the starting flag now that initialization is complete
-        engine.finish_starting();
+        instance.manager.finish_starting();
     } else {
         // Engine already running/starting - wait a bit for it to be ready
         // This handles React StrictMode re-mount
         tokio::time::sleep(tokio::time::Duration::from_millis(500)).await;
     }
 
-    // Connect to the engine's WebSocket server (will skip if already connected)
-    ipc.connect().await?;
+    // Connect to the engine's IPC server (will skip if already connected)
+    if let Some(endpoint) = instance.manager.ipc_endpoint() {
+        instance.ipc.set_endpoint(endpoint).await;
+    }
+    instance.ipc.connect().await?;
 
     Ok(())
 }
 
-/// Resize the engine viewport
+/// Resize an engine instance's viewport
 #[tauri::command]
 pub async fn resize_engine_viewport(
-    ipc: State<'_, IpcBridge>,
+    pool: State<'_, EnginePool>,
+    instance_id: String,
     width: u32,
     height: u32,
 ) -> Result<(), String> {
+    let instance = pool.get(&instance_id)?;
     let params = json!({
         "width": width,
         "height": height,
     });
 
-    ipc.send_command("window.resize", params).await?;
+    instance.ipc.send_command("window.resize", params).await?;
     Ok(())
 }
 
-/// Set engine window bounds (position and size) for overlay mode
+/// Set an engine instance's window bounds (position and size) for overlay mode
 #[tauri::command]
 pub async fn set_engine_bounds(
-    ipc: State<'_, IpcBridge>,
+    pool: State<'_, EnginePool>,
+    instance_id: String,
     x: i32,
     y: i32,
     width: u32,
     height: u32,
 ) -> Result<(), String> {
+    let instance = pool.get(&instance_id)?;
     let params = json!({
         "x": x,
         "y": y,
@@ -232,34 +522,38 @@ pub async fn set_engine_bounds(
         "height": height,
     });
 
-    ipc.send_command("window.set_bounds", params).await?;
+    instance.ipc.send_command("window.set_bounds", params).await?;
     Ok(())
 }
 
-/// Set engine window owner for z-order (overlay stays above owner)
+/// Set an engine instance's window owner for z-order (overlay stays above owner)
 #[tauri::command]
 pub async fn set_engine_owner(
-    ipc: State<'_, IpcBridge>,
+    pool: State<'_, EnginePool>,
+    instance_id: String,
     hwnd: u64,
 ) -> Result<(), String> {
+    let instance = pool.get(&instance_id)?;
     let params = json!({
         "hwnd": hwnd,
     });
 
-    ipc.send_command("window.set_owner", params).await?;
+    instance.ipc.send_command("window.set_owner", params).await?;
     Ok(())
 }
 
-/// Enable low-latency follow mode - engine polls owner window position directly
+/// Enable low-latency follow mode - the instance polls owner window position directly
 #[tauri::command]
 pub async fn set_engine_follow(
-    ipc: State<'_, IpcBridge>,
+    pool: State<'_, EnginePool>,
+    instance_id: String,
     follow: bool,
     offset_x: i32,
     offset_y: i32,
     width: u32,
     height: u32,
 ) -> Result<(), String> {
+    let instance = pool.get(&instance_id)?;
     let params = json!({
         "follow": follow,
         "offset_x": offset_x,
@@ -268,14 +562,18 @@ pub async fn set_engine_follow(
         "height": height,
     });
 
-    ipc.send_command("window.set_follow", params).await?;
+    instance.ipc.send_command("window.set_follow", params).await?;
     Ok(())
 }
 
-/// Show the engine window (call after positioning)
+/// Show an engine instance's window (call after positioning)
 #[tauri::command]
-pub async fn show_engine(ipc: State<'_, IpcBridge>) -> Result<(), String> {
-    ipc.send_command("window.show", json!({})).await?;
+pub async fn show_engine(
+    pool: State<'_, EnginePool>,
+    instance_id: String,
+) -> Result<(), String> {
+    let instance = pool.get(&instance_id)?;
+    instance.ipc.send_command("window.show", json!({})).await?;
     Ok(())
 }
 
@@ -299,17 +597,106 @@ pub async fn get_window_hwnd(app: AppHandle) -> Result<u64, String> {
     }
 }
 
+/// Get the most recent lines captured from an engine instance's stdout/stderr
+#[tauri::command]
+pub async fn get_engine_logs(
+    pool: State<'_, EnginePool>,
+    instance_id: String,
+) -> Result<Vec<String>, String> {
+    let instance = pool.get(&instance_id)?;
+    Ok(instance.manager.recent_logs())
+}
+
+/// Enable or disable automatic restart-with-backoff when an engine instance crashes.
+#[tauri::command]
+pub async fn set_engine_supervised(
+    pool: State<'_, EnginePool>,
+    instance_id: String,
+    enabled: bool,
+) -> Result<(), String> {
+    let instance = pool.get(&instance_id)?;
+    let engine = &instance.manager;
+    if enabled {
+        engine.enable_supervisor(RestartPolicy::default());
+    } else {
+        engine.disable_supervisor();
+    }
+    Ok(())
+}
+
 // ═══════════════════════════════════════════════════════════════════════════
 // CLAUDE CODE INTEGRATION
 // ═══════════════════════════════════════════════════════════════════════════
 
 #[derive(Debug, Clone, Serialize)]
 pub struct ClaudeStreamEvent {
-    pub event_type: String,  // "start", "chunk", "done", "error"
+    pub event_type: String,  // "start", "chunk", "progress", "done", "error", "cancelled"
     pub content: String,
     pub conversation_id: String,
 }
 
+/// A handle to an in-flight Claude CLI child process, kept in
+/// `ClaudeProcessRegistry` so `cancel_claude_message` can find and signal
+/// it by conversation id without holding the streaming task's own state.
+pub struct ClaudeProcessHandle {
+    /// pid of the spawned child, which is also its process group id since
+    /// we launch it with `process_group(0)` on Unix - killing the group
+    /// takes down the CLI's Node children too. On Windows `taskkill /T`
+    /// does the same for `claude.cmd`'s wrapped cmd.exe/node tree.
+    pid: u32,
+    cancelled: Arc<AtomicBool>,
+}
+
+/// Registry of in-flight Claude CLI processes, managed as Tauri state and
+/// keyed by conversation id.
+pub type ClaudeProcessRegistry = Mutex<HashMap<String, ClaudeProcessHandle>>;
+
+/// Cancel an in-flight Claude CLI invocation by conversation id. Terminates
+/// the whole process group rather than just the immediate child, since the
+/// CLI may be wrapped in `cmd.exe` (npm installs) or spawn its own Node
+/// subprocesses.
+#[tauri::command]
+pub async fn cancel_claude_message(
+    conversation_id: String,
+    registry: State<'_, ClaudeProcessRegistry>,
+) -> Result<(), String> {
+    let handle = registry
+        .lock()
+        .map_err(|e| e.to_string())?
+        .remove(&conversation_id);
+
+    let handle = match handle {
+        Some(h) => h,
+        None => return Ok(()), // already finished or unknown - nothing to do
+    };
+
+    handle.cancelled.store(true, Ordering::SeqCst);
+    println!("[Claude] Cancelling conversation {} (pid {})", conversation_id, handle.pid);
+
+    #[cfg(unix)]
+    {
+        let _ = std::process::Command::new("kill")
+            .args(["-TERM", &format!("-{}", handle.pid)])
+            .status();
+        tokio::time::sleep(Duration::from_millis(500)).await;
+        let _ = std::process::Command::new("kill")
+            .args(["-KILL", &format!("-{}", handle.pid)])
+            .status();
+    }
+
+    #[cfg(windows)]
+    {
+        // /T kills the whole tree - npm-installed claude.cmd wraps a node.exe
+        // child, and a plain SIGTERM-equivalent to just the cmd.exe shell
+        // would leave it running.
+        let _ = std::process::Command::new("taskkill")
+            .args(["/PID", &handle.pid.to_string(), "/T", "/F"])
+            .status();
+    }
+
+    Ok(())
+}
+
 /// Find the claude executable path
 fn find_claude_path() -> Option<String> {
     // On Windows, prefer .cmd files from npm
@@ -413,53 +800,37 @@ pub async fn check_claude_available() -> Result<bool, String> {
     Ok(find_claude_path().is_some())
 }
 
-/// Open a terminal window for Claude authentication
+/// Open a terminal window for Claude authentication, via a configurable
+/// `TerminalLauncher` instead of one hardcoded terminal invocation per OS.
+/// `shell` lets the frontend pass a user-configured terminal from settings;
+/// it defaults to `terminal::default_shell()` when omitted.
 #[tauri::command]
-pub async fn open_claude_auth() -> Result<(), String> {
+pub async fn open_claude_auth(shell: Option<Shell>) -> Result<(), String> {
     let claude_path = find_claude_path()
         .ok_or_else(|| "Claude Code CLI not found".to_string())?;
 
     println!("[Claude] Opening terminal for authentication...");
 
-    #[cfg(windows)]
-    {
-        // On Windows, open a new cmd window with claude running
-        std::process::Command::new("cmd")
-            .args(["/c", "start", "cmd", "/k", &claude_path])
-            .spawn()
-            .map_err(|e| format!("Failed to open terminal: {}", e))?;
-    }
-
-    #[cfg(target_os = "macos")]
-    {
-        // On macOS, open Terminal.app
-        std::process::Command::new("osascript")
-            .args(["-e", &format!("tell app \"Terminal\" to do script \"{}\"", claude_path)])
-            .spawn()
-            .map_err(|e| format!("Failed to open terminal: {}", e))?;
-    }
+    let primary = shell.unwrap_or_else(terminal::default_shell);
+    let mut last_err = match TerminalLauncher::new(primary).launch(&claude_path) {
+        Ok(_) => return Ok(()),
+        Err(e) => e,
+    };
 
-    #[cfg(target_os = "linux")]
+    // If the configured/default Unix terminal wasn't found, fall back
+    // through a short list of other common emulators rather than failing
+    // outright, mirroring the old per-OS hardcoded fallback list.
+    #[cfg(all(unix, not(target_os = "macos")))]
     {
-        // On Linux, try common terminal emulators
-        let terminals = ["gnome-terminal", "konsole", "xterm", "x-terminal-emulator"];
-        let mut opened = false;
-        for term in terminals {
-            if std::process::Command::new(term)
-                .args(["--", &claude_path])
-                .spawn()
-                .is_ok()
-            {
-                opened = true;
-                break;
+        for candidate in ["gnome-terminal", "konsole", "xterm", "x-terminal-emulator"] {
+            match TerminalLauncher::new(Shell::UnixShell(candidate.to_string())).launch(&claude_path) {
+                Ok(_) => return Ok(()),
+                Err(e) => last_err = e,
             }
         }
-        if !opened {
-            return Err("Could not find a terminal emulator".to_string());
-        }
     }
 
-    Ok(())
+    Err(last_err)
 }
 
 /// Get the path to claude executable
@@ -475,6 +846,7 @@ pub async fn send_claude_message(
     message: String,
     conversation_id: String,
     working_dir: Option<String>,
+    registry: State<'_, ClaudeProcessRegistry>,
 ) -> Result<(), String> {
     // Find claude executable
     let claude_path = find_claude_path()
@@ -538,6 +910,14 @@ pub async fn send_claude_message(
         cmd.current_dir(dir);
     }
 
+    // Launch in its own process group so cancel_claude_message can signal
+    // the whole tree (Node children included) instead of just this pid.
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::CommandExt;
+        cmd.process_group(0);
+    }
+
     // Spawn the process
     let mut child = match cmd.spawn() {
         Ok(c) => c,
@@ -555,6 +935,14 @@ pub async fn send_claude_message(
 
     println!("[Claude] Process spawned successfully");
 
+    let cancelled = Arc::new(AtomicBool::new(false));
+    if let Some(pid) = child.id() {
+        registry.lock().map_err(|e| e.to_string())?.insert(
+            conversation_id.clone(),
+            ClaudeProcessHandle { pid, cancelled: cancelled.clone() },
+        );
+    }
+
     // Write the message to stdin
     if let Some(mut stdin) = child.stdin.take() {
         use tokio::io::AsyncWriteExt;
@@ -584,6 +972,10 @@ pub async fn send_claude_message(
         let mut stdout = stdout;
         let mut full_response = String::new();
         let mut buffer = [0u8; 256]; // Small buffer for responsive streaming
+        // Retained across reads and decoded via `drain_utf8` - a 256-byte
+        // read lands mid-multibyte-char often enough that decoding each raw
+        // read on its own would silently drop the whole chunk.
+        let mut pending: Vec<u8> = Vec::new();
         let mut chunk_count = 0;
 
         loop {
@@ -591,7 +983,9 @@ pub async fn send_claude_message(
                 Ok(0) => break, // EOF
                 Ok(n) => {
                     chunk_count += 1;
-                    if let Ok(text) = String::from_utf8(buffer[..n].to_vec()) {
+                    pending.extend_from_slice(&buffer[..n]);
+                    let text = drain_utf8(&mut pending);
+                    if !text.is_empty() {
                         println!("[Claude] stdout chunk {}: {} bytes", chunk_count, n);
                         full_response.push_str(&text);
 
@@ -612,6 +1006,10 @@ pub async fn send_claude_message(
             }
         }
 
+        if !pending.is_empty() {
+            full_response.push_str(&String::from_utf8_lossy(&pending));
+        }
+
         println!("[Claude] stdout done, {} chunks, {} total chars", chunk_count, full_response.len());
         full_response
     });
@@ -623,12 +1021,15 @@ pub async fn send_claude_message(
         let mut stderr = stderr;
         let mut stderr_content = String::new();
         let mut buffer = [0u8; 1024];
+        let mut pending: Vec<u8> = Vec::new();
 
         loop {
             match stderr.read(&mut buffer).await {
                 Ok(0) => break,
                 Ok(n) => {
-                    if let Ok(text) = String::from_utf8(buffer[..n].to_vec()) {
+                    pending.extend_from_slice(&buffer[..n]);
+                    let text = drain_utf8(&mut pending);
+                    if !text.is_empty() {
                         println!("[Claude] stderr: {}", text);
                         stderr_content.push_str(&text);
 
@@ -644,6 +1045,10 @@ pub async fn send_claude_message(
             }
         }
 
+        if !pending.is_empty() {
+            stderr_content.push_str(&String::from_utf8_lossy(&pending));
+        }
+
         stderr_content
     });
 
@@ -677,6 +1082,17 @@ pub async fn send_claude_message(
     let (full_response, stderr_content, status_result) = match result {
         Ok(data) => data,
         Err(_) => {
+            registry.lock().map_err(|e| e.to_string())?.remove(&conversation_id);
+
+            if cancelled.load(Ordering::SeqCst) {
+                let _ = app.emit("claude-stream", ClaudeStreamEvent {
+                    event_type: "cancelled".to_string(),
+                    content: "".to_string(),
+                    conversation_id: conversation_id.clone(),
+                });
+                return Ok(());
+            }
+
             println!("[Claude] Process timed out after {} seconds", timeout_duration.as_secs());
             let error_msg = format!(
                 "Claude process timed out after {} seconds. This usually means Claude is waiting for authentication. \
@@ -692,6 +1108,17 @@ pub async fn send_claude_message(
         }
     };
 
+    registry.lock().map_err(|e| e.to_string())?.remove(&conversation_id);
+
+    if cancelled.load(Ordering::SeqCst) {
+        let _ = app.emit("claude-stream", ClaudeStreamEvent {
+            event_type: "cancelled".to_string(),
+            content: "".to_string(),
+            conversation_id: conversation_id.clone(),
+        });
+        return Ok(());
+    }
+
     let status = status_result.map_err(|e| format!("Error waiting for process: {}", e))?;
 
     println!("[Claude] Process exited with status: {}", status);
@@ -741,6 +1168,509 @@ pub async fn send_claude_message(
     Ok(())
 }
 
+/// A structured event parsed from the Claude CLI's `stream-json` output.
+/// Unlike `ClaudeStreamEvent`'s opaque text chunks, this distinguishes
+/// assistant text from thinking blocks, tool calls, and tool results, plus
+/// the final usage summary.
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "kind")]
+pub enum ClaudeStreamJsonEvent {
+    Text { conversation_id: String, text: String },
+    Thinking { conversation_id: String, text: String },
+    ToolUse { conversation_id: String, name: String, input: Value },
+    ToolResult { conversation_id: String, content: Value },
+    Result {
+        conversation_id: String,
+        total_cost_usd: Option<f64>,
+        duration_ms: Option<u64>,
+        num_turns: Option<u32>,
+        session_id: Option<String>,
+    },
+    /// A line that wasn't valid/expected NDJSON - degrade to raw text
+    /// instead of aborting the stream.
+    Raw { conversation_id: String, text: String },
+    Done { conversation_id: String },
+    Cancelled { conversation_id: String },
+    Error { conversation_id: String, message: String },
+}
+
+/// One line of the CLI's `--output-format stream-json` NDJSON output,
+/// discriminated by its `"type"` field.
+#[derive(Debug, Deserialize)]
+#[serde(tag = "type")]
+enum ClaudeCliLine {
+    #[serde(rename = "system")]
+    System {},
+    #[serde(rename = "assistant")]
+    Assistant { message: ClaudeCliMessage },
+    #[serde(rename = "user")]
+    User {},
+    #[serde(rename = "result")]
+    Result {
+        #[serde(default)]
+        total_cost_usd: Option<f64>,
+        #[serde(default)]
+        duration_ms: Option<u64>,
+        #[serde(default)]
+        num_turns: Option<u32>,
+        #[serde(default)]
+        session_id: Option<String>,
+    },
+}
+
+#[derive(Debug, Deserialize)]
+struct ClaudeCliMessage {
+    #[serde(default)]
+    content: Vec<Value>,
+}
+
+fn emit_content_block(app: &AppHandle, conversation_id: &str, block: &Value) {
+    let event = match block.get("type").and_then(|t| t.as_str()) {
+        Some("text") => ClaudeStreamJsonEvent::Text {
+            conversation_id: conversation_id.to_string(),
+            text: block["text"].as_str().unwrap_or_default().to_string(),
+        },
+        Some("thinking") => ClaudeStreamJsonEvent::Thinking {
+            conversation_id: conversation_id.to_string(),
+            text: block["thinking"].as_str().unwrap_or_default().to_string(),
+        },
+        Some("tool_use") => ClaudeStreamJsonEvent::ToolUse {
+            conversation_id: conversation_id.to_string(),
+            name: block["name"].as_str().unwrap_or_default().to_string(),
+            input: block["input"].clone(),
+        },
+        Some("tool_result") => ClaudeStreamJsonEvent::ToolResult {
+            conversation_id: conversation_id.to_string(),
+            content: block["content"].clone(),
+        },
+        _ => return,
+    };
+
+    let _ = app.emit("claude-stream-json", event);
+}
+
+/// Parse one NDJSON line and emit the events it implies, degrading to a raw
+/// text event rather than aborting the stream on malformed input.
+fn handle_ndjson_line(app: &AppHandle, conversation_id: &str, line: &str) {
+    if line.trim().is_empty() {
+        return;
+    }
+
+    match serde_json::from_str::<ClaudeCliLine>(line) {
+        Ok(ClaudeCliLine::Assistant { message }) => {
+            for block in &message.content {
+                emit_content_block(app, conversation_id, block);
+            }
+        }
+        Ok(ClaudeCliLine::Result {
+            total_cost_usd,
+            duration_ms,
+            num_turns,
+            session_id,
+        }) => {
+            let _ = app.emit(
+                "claude-stream-json",
+                ClaudeStreamJsonEvent::Result {
+                    conversation_id: conversation_id.to_string(),
+                    total_cost_usd,
+                    duration_ms,
+                    num_turns,
+                    session_id,
+                },
+            );
+        }
+        Ok(ClaudeCliLine::System {}) | Ok(ClaudeCliLine::User {}) => {}
+        Err(_) => {
+            let _ = app.emit(
+                "claude-stream-json",
+                ClaudeStreamJsonEvent::Raw {
+                    conversation_id: conversation_id.to_string(),
+                    text: line.to_string(),
+                },
+            );
+        }
+    }
+}
+
+/// Send a message to Claude Code and stream back structured events instead
+/// of opaque text chunks, by invoking the CLI with `--output-format
+/// stream-json --verbose` and parsing its NDJSON stdout.
+#[tauri::command]
+pub async fn send_claude_message_structured(
+    app: AppHandle,
+    message: String,
+    conversation_id: String,
+    working_dir: Option<String>,
+    registry: State<'_, ClaudeProcessRegistry>,
+) -> Result<(), String> {
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    let claude_path = find_claude_path()
+        .ok_or_else(|| "Claude Code CLI not found. Install it from https://claude.ai/claude-code".to_string())?;
+
+    let is_npm_cmd = cfg!(windows) && (
+        claude_path.ends_with(".cmd") ||
+        claude_path.ends_with(".CMD") ||
+        claude_path.contains("\\npm\\") ||
+        claude_path.contains("/npm/") ||
+        claude_path.contains("\\AppData\\Roaming\\npm")
+    );
+
+    let mut cmd = if is_npm_cmd {
+        let mut c = Command::new("cmd.exe");
+        c.arg("/C").arg(&claude_path);
+        c
+    } else {
+        Command::new(&claude_path)
+    };
+
+    cmd.arg("--print")
+        .arg("--output-format")
+        .arg("stream-json")
+        .arg("--verbose")
+        .arg("--dangerously-skip-permissions")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+
+    cmd.env_remove("ANTHROPIC_API_KEY");
+
+    if let Some(ref dir) = working_dir {
+        cmd.current_dir(dir);
+    }
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::CommandExt;
+        cmd.process_group(0);
+    }
+
+    let mut child = cmd
+        .spawn()
+        .map_err(|e| format!("Failed to spawn claude: {}. Path: {}", e, claude_path))?;
+
+    let cancelled = Arc::new(AtomicBool::new(false));
+    if let Some(pid) = child.id() {
+        registry.lock().map_err(|e| e.to_string())?.insert(
+            conversation_id.clone(),
+            ClaudeProcessHandle { pid, cancelled: cancelled.clone() },
+        );
+    }
+
+    if let Some(mut stdin) = child.stdin.take() {
+        let _ = stdin.write_all(message.as_bytes()).await;
+        drop(stdin);
+    }
+
+    let stdout = child.stdout.take().ok_or("Failed to capture stdout")?;
+
+    let app_for_stdout = app.clone();
+    let conv_id = conversation_id.clone();
+    let stdout_handle = tokio::spawn(async move {
+        let mut stdout = stdout;
+        let mut chunk = [0u8; 4096];
+        // Retain the trailing incomplete line across reads as raw bytes, not
+        // a `String` - a single read may land in the middle of both a JSON
+        // object and a multibyte UTF-8 sequence, and decoding before the
+        // line is complete would mangle the split character into U+FFFD.
+        let mut pending: Vec<u8> = Vec::new();
+
+        loop {
+            match stdout.read(&mut chunk).await {
+                Ok(0) => break,
+                Ok(n) => {
+                    pending.extend_from_slice(&chunk[..n]);
+                    while let Some(pos) = pending.iter().position(|&b| b == b'\n') {
+                        let line_bytes: Vec<u8> = pending.drain(..=pos).collect();
+                        let line = String::from_utf8_lossy(&line_bytes)
+                            .trim_end_matches(['\r', '\n'])
+                            .to_string();
+                        handle_ndjson_line(&app_for_stdout, &conv_id, &line);
+                    }
+                }
+                Err(_) => break,
+            }
+        }
+
+        if !pending.is_empty() {
+            handle_ndjson_line(&app_for_stdout, &conv_id, &String::from_utf8_lossy(&pending));
+        }
+    });
+
+    let timeout_duration = tokio::time::Duration::from_secs(180);
+    let wait_result = tokio::time::timeout(timeout_duration, async {
+        let _ = stdout_handle.await;
+        child.wait().await
+    })
+    .await;
+
+    registry.lock().map_err(|e| e.to_string())?.remove(&conversation_id);
+
+    if cancelled.load(Ordering::SeqCst) {
+        let _ = app.emit(
+            "claude-stream-json",
+            ClaudeStreamJsonEvent::Cancelled {
+                conversation_id: conversation_id.clone(),
+            },
+        );
+        return Ok(());
+    }
+
+    match wait_result {
+        Ok(Ok(_status)) => {
+            let _ = app.emit(
+                "claude-stream-json",
+                ClaudeStreamJsonEvent::Done {
+                    conversation_id: conversation_id.clone(),
+                },
+            );
+            Ok(())
+        }
+        Ok(Err(e)) => {
+            let message = format!("Error waiting for process: {}", e);
+            let _ = app.emit(
+                "claude-stream-json",
+                ClaudeStreamJsonEvent::Error {
+                    conversation_id: conversation_id.clone(),
+                    message: message.clone(),
+                },
+            );
+            Err(message)
+        }
+        Err(_) => {
+            let message = format!(
+                "Claude process timed out after {} seconds",
+                timeout_duration.as_secs()
+            );
+            let _ = app.emit(
+                "claude-stream-json",
+                ClaudeStreamJsonEvent::Error {
+                    conversation_id: conversation_id.clone(),
+                    message: message.clone(),
+                },
+            );
+            Err(message)
+        }
+    }
+}
+
+/// Reads a Claude session's pty master in a background thread and forwards
+/// each chunk as a `claude-stream` chunk event, mirroring the `LogForwarder`
+/// pattern in `engine_process.rs`.
+struct SessionForwarder {
+    handle: Option<std::thread::JoinHandle<()>>,
+}
+
+/// Pull every complete UTF-8 char out of `pending`, decoding incrementally
+/// rather than waiting for a line: a `claude` TUI prompt (permission/tool
+/// approval confirmations) is drawn with ANSI control sequences and
+/// typically leaves the cursor mid-line with no trailing newline, so
+/// line-buffering here would hold prompt text back until a later newline or
+/// session close - exactly the interactivity this forwarder exists to
+/// provide. Only the trailing partial char (cut off by the read boundary),
+/// if any, is left in `pending` for the next read to complete.
+fn drain_utf8(pending: &mut Vec<u8>) -> String {
+    let mut text = String::new();
+    loop {
+        match std::str::from_utf8(pending) {
+            Ok(valid) => {
+                text.push_str(valid);
+                pending.clear();
+                return text;
+            }
+            Err(e) => {
+                let valid_up_to = e.valid_up_to();
+                text.push_str(&String::from_utf8_lossy(&pending[..valid_up_to]));
+                match e.error_len() {
+                    // Genuinely invalid bytes, not just a sequence cut off by
+                    // the read boundary - drop them and keep decoding what
+                    // follows, or we'd spin forever re-decoding them.
+                    Some(invalid_len) => {
+                        pending.drain(..valid_up_to + invalid_len);
+                    }
+                    None => {
+                        pending.drain(..valid_up_to);
+                        return text;
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl SessionForwarder {
+    fn spawn(
+        mut reader: Box<dyn std::io::Read + Send>,
+        app: AppHandle,
+        conversation_id: String,
+    ) -> Self {
+        let handle = std::thread::spawn(move || {
+            let mut chunk = [0u8; 4096];
+            let mut pending: Vec<u8> = Vec::new();
+
+            loop {
+                match reader.read(&mut chunk) {
+                    Ok(0) => break,
+                    Ok(n) => {
+                        pending.extend_from_slice(&chunk[..n]);
+                        let text = drain_utf8(&mut pending);
+                        if !text.is_empty() {
+                            let _ = app.emit("claude-stream", ClaudeStreamEvent {
+                                event_type: "chunk".to_string(),
+                                content: text,
+                                conversation_id: conversation_id.clone(),
+                            });
+                        }
+                    }
+                    Err(_) => break,
+                }
+            }
+
+            if !pending.is_empty() {
+                let text = String::from_utf8_lossy(&pending).into_owned();
+                let _ = app.emit("claude-stream", ClaudeStreamEvent {
+                    event_type: "chunk".to_string(),
+                    content: text,
+                    conversation_id: conversation_id.clone(),
+                });
+            }
+
+            let _ = app.emit("claude-stream", ClaudeStreamEvent {
+                event_type: "done".to_string(),
+                content: String::new(),
+                conversation_id: conversation_id.clone(),
+            });
+        });
+
+        Self { handle: Some(handle) }
+    }
+
+    fn join(&mut self) {
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+/// A live Claude CLI process attached to a pseudo-terminal, kept open for the
+/// lifetime of a conversation so follow-up turns share context and
+/// authentication instead of each spawning a fresh `--print` process.
+struct ClaudeSession {
+    writer: Box<dyn std::io::Write + Send>,
+    child: Box<dyn portable_pty::Child + Send + Sync>,
+    forwarder: SessionForwarder,
+}
+
+/// Open Claude PTY sessions, managed as Tauri state and keyed by conversation id.
+pub type ClaudeSessionRegistry = Mutex<HashMap<String, ClaudeSession>>;
+
+/// Open a persistent, interactive Claude session attached to a pty. Unlike
+/// `send_claude_message`, this does not pass `--dangerously-skip-permissions` -
+/// the pty lets permission and tool-approval prompts be answered through
+/// `claude_session_send` instead of being bypassed.
+#[tauri::command]
+pub async fn claude_session_open(
+    app: AppHandle,
+    conversation_id: String,
+    working_dir: Option<String>,
+    sessions: State<'_, ClaudeSessionRegistry>,
+) -> Result<(), String> {
+    let claude_path = find_claude_path()
+        .ok_or_else(|| "Claude Code CLI not found. Install it from https://claude.ai/claude-code".to_string())?;
+
+    let pty_system = portable_pty::native_pty_system();
+    let pair = pty_system
+        .openpty(portable_pty::PtySize {
+            rows: 24,
+            cols: 120,
+            pixel_width: 0,
+            pixel_height: 0,
+        })
+        .map_err(|e| format!("Failed to open pty: {}", e))?;
+
+    let mut cmd = portable_pty::CommandBuilder::new(&claude_path);
+    cmd.env_remove("ANTHROPIC_API_KEY");
+    if let Some(dir) = &working_dir {
+        cmd.cwd(dir);
+    }
+
+    let child = pair
+        .slave
+        .spawn_command(cmd)
+        .map_err(|e| format!("Failed to spawn claude: {}", e))?;
+    // Drop our end of the slave fd once the child has inherited it, or the
+    // master never sees EOF when the child exits.
+    drop(pair.slave);
+
+    let reader = pair
+        .master
+        .try_clone_reader()
+        .map_err(|e| format!("Failed to clone pty reader: {}", e))?;
+    let writer = pair
+        .master
+        .take_writer()
+        .map_err(|e| format!("Failed to take pty writer: {}", e))?;
+
+    let _ = app.emit("claude-stream", ClaudeStreamEvent {
+        event_type: "start".to_string(),
+        content: String::new(),
+        conversation_id: conversation_id.clone(),
+    });
+
+    let forwarder = SessionForwarder::spawn(reader, app.clone(), conversation_id.clone());
+
+    sessions.lock().map_err(|e| e.to_string())?.insert(
+        conversation_id,
+        ClaudeSession { writer, child, forwarder },
+    );
+
+    Ok(())
+}
+
+/// Send one more turn to an already-open Claude session, reusing its live
+/// process so context and authentication carry over from prior turns.
+#[tauri::command]
+pub async fn claude_session_send(
+    conversation_id: String,
+    message: String,
+    sessions: State<'_, ClaudeSessionRegistry>,
+) -> Result<(), String> {
+    use std::io::Write;
+
+    let mut guard = sessions.lock().map_err(|e| e.to_string())?;
+    let session = guard
+        .get_mut(&conversation_id)
+        .ok_or_else(|| format!("No open Claude session for conversation {}", conversation_id))?;
+
+    session
+        .writer
+        .write_all(message.as_bytes())
+        .and_then(|_| session.writer.write_all(b"\n"))
+        .map_err(|e| format!("Failed to write to Claude session: {}", e))
+}
+
+/// Close an open Claude session: drop the writer so Claude sees the hangup,
+/// kill the child if it hasn't already exited, and reap it.
+#[tauri::command]
+pub async fn claude_session_close(
+    conversation_id: String,
+    sessions: State<'_, ClaudeSessionRegistry>,
+) -> Result<(), String> {
+    let session = sessions.lock().map_err(|e| e.to_string())?.remove(&conversation_id);
+
+    let mut session = match session {
+        Some(s) => s,
+        None => return Ok(()),
+    };
+
+    drop(session.writer);
+    let _ = session.child.kill();
+    let _ = session.child.wait();
+    session.forwarder.join();
+
+    Ok(())
+}
+
 // ═══════════════════════════════════════════════════════════════════════════
 // FILE SYSTEM WATCHING
 // ═══════════════════════════════════════════════════════════════════════════
@@ -1028,6 +1958,119 @@ pub async fn stop_file_watcher(
     Ok(())
 }
 
+/// Batch of asset paths pushed to the frontend after the asset watcher's
+/// debounce window closes.
+#[derive(Debug, Clone, Serialize)]
+pub struct AssetChangedEvent {
+    pub paths: Vec<String>,
+}
+
+/// State for the asset hot-reload watcher. Kept separate from
+/// `FileWatcherState` (which only notifies the frontend) since this one also
+/// pushes an `assets.reload` message to the running engine over IPC.
+#[derive(Default)]
+pub struct AssetWatcherState {
+    debouncer: Option<notify_debouncer_mini::Debouncer<notify::RecommendedWatcher>>,
+}
+
+/// Start watching a directory for asset changes. Each debounce window's
+/// batch of changed paths is pushed to the engine as an `assets.reload` IPC
+/// message and mirrored to the frontend as an `asset-changed` event, so a
+/// storm of saves (e.g. a build writing many files at once) becomes a single
+/// reload instead of one per file.
+#[tauri::command]
+pub async fn start_asset_watch(
+    app: AppHandle,
+    asset_watcher: State<'_, Mutex<AssetWatcherState>>,
+    path: String,
+    debounce_ms: Option<u64>,
+) -> Result<(), String> {
+    let watch_path = PathBuf::from(&path);
+
+    if !watch_path.exists() {
+        return Err(format!("Directory does not exist: {}", path));
+    }
+
+    println!("[AssetWatch] Starting asset watcher for: {}", path);
+
+    // Stop any existing watcher
+    {
+        let mut state = asset_watcher.lock().map_err(|e| e.to_string())?;
+        state.debouncer = None;
+    }
+
+    let app_handle = app.clone();
+    let debouncer = new_debouncer(
+        Duration::from_millis(debounce_ms.unwrap_or(300)),
+        move |result: Result<Vec<notify_debouncer_mini::DebouncedEvent>, notify::Error>| {
+            let paths: Vec<String> = match result {
+                Ok(events) => events
+                    .into_iter()
+                    .map(|event| event.path.to_string_lossy().to_string())
+                    .collect(),
+                Err(e) => {
+                    println!("[AssetWatch] Error: {:?}", e);
+                    return;
+                }
+            };
+
+            if paths.is_empty() {
+                return;
+            }
+
+            println!("[AssetWatch] {} asset(s) changed", paths.len());
+
+            if let Err(e) = app_handle.emit("asset-changed", AssetChangedEvent { paths: paths.clone() }) {
+                println!("[AssetWatch] Failed to emit asset-changed event: {}", e);
+            }
+
+            let app_for_ipc = app_handle.clone();
+            tauri::async_runtime::spawn(async move {
+                let Some(pool) = app_for_ipc.try_state::<EnginePool>() else {
+                    return;
+                };
+                for instance in pool.all() {
+                    if let Err(e) = instance
+                        .ipc
+                        .send_command("assets.reload", json!({ "paths": paths.clone() }))
+                        .await
+                    {
+                        println!("[AssetWatch] Failed to push assets.reload over IPC: {}", e);
+                    }
+                }
+            });
+        },
+    )
+    .map_err(|e| format!("Failed to create asset watcher: {}", e))?;
+
+    // Start watching
+    {
+        let mut state = asset_watcher.lock().map_err(|e| e.to_string())?;
+        let mut debouncer = debouncer;
+        debouncer.watcher().watch(&watch_path, RecursiveMode::Recursive)
+            .map_err(|e| format!("Failed to start watching: {}", e))?;
+
+        state.debouncer = Some(debouncer);
+    }
+
+    println!("[AssetWatch] Asset watcher started successfully");
+    Ok(())
+}
+
+/// Stop the asset watcher.
+#[tauri::command]
+pub async fn stop_asset_watch(
+    asset_watcher: State<'_, Mutex<AssetWatcherState>>,
+) -> Result<(), String> {
+    println!("[AssetWatch] Stopping asset watcher");
+
+    let mut state = asset_watcher.lock().map_err(|e| e.to_string())?;
+    state.debouncer = None;
+
+    println!("[AssetWatch] Asset watcher stopped");
+    Ok(())
+}
+
 // ═══════════════════════════════════════════════════════════════════════════
 // PALETTE / PREFAB FILE SYSTEM
 // ═══════════════════════════════════════════════════════════════════════════
@@ -1,40 +1,489 @@
+use std::collections::VecDeque;
+use std::io::Read;
+use std::process::{Child, ChildStderr, ChildStdin, ChildStdout, Command, ExitStatus, Stdio};
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+use std::sync::{Arc, Mutex, Weak};
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
+
+use crate::ipc_bridge::IpcEndpoint;
+use crate::platform::{self, PlatformBackend};
+
+/// Default grace period `Drop` gives the engine to exit on its own before
+/// escalating to a hard kill.
+const DROP_GRACE_PERIOD: Duration = Duration::from_millis(500);
+
+use serde::Serialize;
+use tauri::{AppHandle, Emitter};
+
 use std::path::PathBuf;
-use std::process::{Child, Command};
-use std::sync::Mutex;
+
+/// How many log lines to retain in the ring buffer for `recent_logs()`.
+const LOG_BUFFER_LINES: usize = 500;
+
+/// How often the reaper thread polls `Child::try_wait()`.
+const REAP_POLL_INTERVAL: Duration = Duration::from_millis(200);
+
+/// Configurable auto-restart behavior for an unexpectedly-exited engine.
+/// Backoff doubles after each failed attempt, capped at `max_backoff`, and
+/// the attempt counter resets once the engine has stayed up past
+/// `stability_threshold`.
+#[derive(Debug, Clone, Copy)]
+pub struct RestartPolicy {
+    pub max_attempts: u32,
+    pub initial_backoff: Duration,
+    pub max_backoff: Duration,
+    pub stability_threshold: Duration,
+}
+
+impl Default for RestartPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 5,
+            initial_backoff: Duration::from_millis(250),
+            max_backoff: Duration::from_secs(8),
+            stability_threshold: Duration::from_secs(10),
+        }
+    }
+}
+
+/// How the IPC port is picked for a launch: a caller-pinned value, or probed
+/// for a free one at spawn time so multiple editor instances don't collide.
+#[derive(Debug, Clone, Copy)]
+enum PortSelection {
+    Fixed(u16),
+    Auto,
+}
+
+/// How the engine's IPC transport is picked for a launch. `Native` is the
+/// default - a Unix domain socket or Windows named pipe, namespaced per
+/// launch so sibling instances never collide. `Tcp` is an explicit opt-in
+/// fallback for setups that need a plain loopback port (e.g. a remote
+/// debugger attaching to the IPC channel).
+#[derive(Debug, Clone, Copy)]
+enum TransportSelection {
+    Native,
+    Tcp(PortSelection),
+}
+
+/// Builder for how the engine subprocess is launched: renderer/editor-mode
+/// flags, extra CLI args, extra environment variables, and IPC transport
+/// selection. Mirrors the env/arg builder style of `std::process::Command`.
+#[derive(Debug, Clone)]
+pub struct LaunchConfig {
+    no_vulkan: bool,
+    editor_mode: bool,
+    extra_args: Vec<String>,
+    env: Vec<(String, String)>,
+    transport: TransportSelection,
+}
+
+impl Default for LaunchConfig {
+    fn default() -> Self {
+        Self {
+            no_vulkan: true,
+            editor_mode: true,
+            extra_args: Vec::new(),
+            env: Vec::new(),
+            transport: TransportSelection::Native,
+        }
+    }
+}
+
+impl LaunchConfig {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn no_vulkan(mut self, enabled: bool) -> Self {
+        self.no_vulkan = enabled;
+        self
+    }
+
+    pub fn editor_mode(mut self, enabled: bool) -> Self {
+        self.editor_mode = enabled;
+        self
+    }
+
+    pub fn arg(mut self, arg: impl Into<String>) -> Self {
+        self.extra_args.push(arg.into());
+        self
+    }
+
+    pub fn args(mut self, args: impl IntoIterator<Item = String>) -> Self {
+        self.extra_args.extend(args);
+        self
+    }
+
+    pub fn env(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.env.push((key.into(), value.into()));
+        self
+    }
+
+    /// Opt into the TCP fallback transport, pinned to a fixed port, instead
+    /// of the default Unix socket/named pipe transport.
+    pub fn ipc_port(mut self, port: u16) -> Self {
+        self.transport = TransportSelection::Tcp(PortSelection::Fixed(port));
+        self
+    }
+
+    /// Opt into the TCP fallback transport, probing for a free port at spawn
+    /// time instead of using a fixed one, so multiple editor instances can
+    /// run their engines side by side.
+    pub fn auto_ipc_port(mut self) -> Self {
+        self.transport = TransportSelection::Tcp(PortSelection::Auto);
+        self
+    }
+}
+
+/// Bind to port 0 and read back the port the OS assigned, then drop the
+/// listener so the engine can bind it. Small TOCTOU window, same tradeoff
+/// `duct`-style port probing makes in practice.
+fn probe_free_port() -> Option<u16> {
+    std::net::TcpListener::bind(("127.0.0.1", 0))
+        .ok()
+        .and_then(|listener| listener.local_addr().ok())
+        .map(|addr| addr.port())
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct EngineLogEvent {
+    pub stream: String, // "stdout" | "stderr"
+    pub line: String,
+}
+
+/// Emitted when the engine process terminates on its own (not via `stop_with_force`).
+#[derive(Debug, Clone, Serialize)]
+pub struct EngineExitEvent {
+    pub crashed: bool,
+    pub code: Option<i32>,
+}
+
+/// Reads a child's stdout/stderr pipe in a background thread, splitting the
+/// incoming bytes on newlines and forwarding complete lines into the shared
+/// ring buffer and as Tauri events. Modeled on the incremental, non-blocking
+/// forwarder pattern used by the `cc` crate's `StderrForwarder`.
+struct LogForwarder {
+    handle: Option<JoinHandle<()>>,
+}
+
+impl LogForwarder {
+    fn spawn<R: Read + Send + 'static>(
+        mut pipe: R,
+        stream_name: &'static str,
+        app: AppHandle,
+        log_buffer: Arc<Mutex<VecDeque<String>>>,
+    ) -> Self {
+        let handle = std::thread::spawn(move || {
+            let mut chunk = [0u8; 4096];
+            let mut pending: Vec<u8> = Vec::new();
+
+            loop {
+                match pipe.read(&mut chunk) {
+                    Ok(0) => {
+                        // EOF - flush whatever partial line is left over
+                        if !pending.is_empty() {
+                            emit_line(
+                                &app,
+                                &log_buffer,
+                                stream_name,
+                                String::from_utf8_lossy(&pending).into_owned(),
+                            );
+                        }
+                        break;
+                    }
+                    Ok(n) => {
+                        pending.extend_from_slice(&chunk[..n]);
+
+                        while let Some(pos) = pending.iter().position(|&b| b == b'\n') {
+                            let line_bytes: Vec<u8> = pending.drain(..=pos).collect();
+                            let line = String::from_utf8_lossy(&line_bytes)
+                                .trim_end_matches(['\r', '\n'])
+                                .to_string();
+                            emit_line(&app, &log_buffer, stream_name, line);
+                        }
+                    }
+                    Err(_) => break,
+                }
+            }
+        });
+
+        Self {
+            handle: Some(handle),
+        }
+    }
+
+    fn join(&mut self) {
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+fn emit_line(
+    app: &AppHandle,
+    log_buffer: &Arc<Mutex<VecDeque<String>>>,
+    stream_name: &str,
+    line: String,
+) {
+    if let Ok(mut buffer) = log_buffer.lock() {
+        if buffer.len() >= LOG_BUFFER_LINES {
+            buffer.pop_front();
+        }
+        buffer.push_back(format!("[{}] {}", stream_name, line));
+    }
+
+    let _ = app.emit(
+        "engine-log",
+        EngineLogEvent {
+            stream: stream_name.to_string(),
+            line,
+        },
+    );
+}
+
+/// Shared liveness state updated by the reaper thread and read by `is_running()`.
+struct ProcessState {
+    running: AtomicBool,
+    exit_status: Mutex<Option<ExitStatus>>,
+}
+
+/// Watches a child process with `Child::try_wait()` instead of polling the OS
+/// process table, mirroring the reaper design in the `async-process` crate.
+/// Reports back through `ProcessState` and, unless `expected_stop` was raised
+/// first, emits an `engine-exit` event distinguishing a crash from a clean exit.
+struct ReaperThread {
+    handle: Option<JoinHandle<()>>,
+}
+
+impl ReaperThread {
+    fn spawn(
+        child: Arc<Mutex<Child>>,
+        state: Arc<ProcessState>,
+        expected_stop: Arc<AtomicBool>,
+        app: AppHandle,
+        manager: Weak<EngineManager>,
+    ) -> Self {
+        let handle = std::thread::spawn(move || loop {
+            std::thread::sleep(REAP_POLL_INTERVAL);
+
+            let status = {
+                let mut guard = match child.lock() {
+                    Ok(guard) => guard,
+                    Err(_) => break,
+                };
+                match guard.try_wait() {
+                    Ok(Some(status)) => Some(status),
+                    Ok(None) => None,
+                    Err(_) => break,
+                }
+            };
+
+            if let Some(status) = status {
+                state.running.store(false, Ordering::SeqCst);
+                *state.exit_status.lock().unwrap() = Some(status);
+
+                if !expected_stop.load(Ordering::SeqCst) {
+                    let crashed = !status.success();
+                    println!(
+                        "Engine process exited unexpectedly ({}): {:?}",
+                        if crashed { "crash" } else { "clean" },
+                        status
+                    );
+                    let _ = app.emit(
+                        "engine-exit",
+                        EngineExitEvent {
+                            crashed,
+                            code: status.code(),
+                        },
+                    );
+
+                    if let Some(manager) = manager.upgrade() {
+                        manager.on_unexpected_exit();
+                    }
+                }
+                break;
+            }
+        });
+
+        Self {
+            handle: Some(handle),
+        }
+    }
+
+    fn join(&mut self) {
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+/// A running engine process plus the background threads watching it.
+struct RunningEngine {
+    child: Arc<Mutex<Child>>,
+    /// Closing this signals the engine to shut down cleanly; taken by
+    /// `stop_graceful` instead of going straight to `kill()`.
+    stdin: Mutex<Option<ChildStdin>>,
+    stdout_forwarder: LogForwarder,
+    stderr_forwarder: LogForwarder,
+    reaper: ReaperThread,
+    state: Arc<ProcessState>,
+    expected_stop: Arc<AtomicBool>,
+}
 
 pub struct EngineManager {
-    process: Mutex<Option<Child>>,
+    app: AppHandle,
+    self_ref: Weak<EngineManager>,
+    backend: Box<dyn PlatformBackend>,
+    process: Mutex<Option<RunningEngine>>,
     engine_path: PathBuf,
-    ipc_port: u16,
-    parent_hwnd: Mutex<Option<u64>>,  // Parent window handle for embedding
-    starting: Mutex<bool>,  // Prevents stop during initialization
+    launch_config: Mutex<LaunchConfig>,
+    /// The IPC endpoint the most recent launch actually bound to (resolved
+    /// from `launch_config`'s `TransportSelection` each time `start` spawns
+    /// the engine). `None` until the first `start()` call.
+    ipc_endpoint: Mutex<Option<IpcEndpoint>>,
+    /// Bumped on every `start()` so each launch gets a uniquely-named socket
+    /// path/pipe name even if the engine's own pid gets reused.
+    launch_seq: AtomicU32,
+    parent_hwnd: Mutex<Option<u64>>, // Parent window handle for embedding
+    starting: Mutex<bool>,           // Prevents stop during initialization
+    log_buffer: Arc<Mutex<VecDeque<String>>>,
+    restart_policy: Mutex<Option<RestartPolicy>>,
+    restart_attempts: AtomicU32,
+    /// Called with the freshly-resolved `IpcEndpoint` after a supervised
+    /// auto-restart relaunches the engine. `start()` mints a new socket
+    /// path/pipe name on every launch (`IpcEndpoint::for_launch` namespaces
+    /// it by `launch_seq`), but only the `start_engine*` commands know to
+    /// point the paired `IpcBridge` at it - a restart triggered from the
+    /// reaper thread has no caller to do that, so it has to reach the
+    /// bridge itself. Set once, when the pool pairs a manager with its
+    /// bridge.
+    restart_hook: Mutex<Option<Box<dyn Fn(IpcEndpoint) + Send + Sync>>>,
 }
 
 impl EngineManager {
-    pub fn new() -> Self {
-        // Try to find engine relative to editor, or use hardcoded path
+    pub fn new(app: AppHandle) -> Arc<Self> {
+        Self::with_config(app, LaunchConfig::default())
+    }
+
+    pub fn with_config(app: AppHandle, launch_config: LaunchConfig) -> Arc<Self> {
+        let backend = platform::current();
+
+        // Try to find engine relative to editor, or fall back to a path
+        // relative to the current working directory.
+        let binary_relative_path = backend.binary_relative_path();
         let engine_path = std::env::current_exe()
             .ok()
             .and_then(|exe_path| {
-                // From: editor/src-tauri/target/release/ascii-dungeon-editor.exe
-                // To:   build/Debug/ascii_dungeon.exe
+                // From: editor/src-tauri/target/release/<editor binary>
+                // To:   <ascii_dungeon root>/<binary_relative_path>
                 exe_path
                     .parent() // target/release
                     .and_then(|p| p.parent()) // target
                     .and_then(|p| p.parent()) // src-tauri
                     .and_then(|p| p.parent()) // editor
                     .and_then(|p| p.parent()) // ascii_dungeon root
-                    .map(|p| p.join("build/Debug/ascii_dungeon.exe"))
+                    .map(|p| p.join(&binary_relative_path))
             })
-            .unwrap_or_else(|| PathBuf::from("D:/repos/ascii_dungeon/build/Debug/ascii_dungeon.exe"));
+            .unwrap_or_else(|| binary_relative_path.clone());
 
-        Self {
+        Arc::new_cyclic(|self_ref| Self {
+            app,
+            self_ref: self_ref.clone(),
+            backend,
             process: Mutex::new(None),
             engine_path,
-            ipc_port: 9999,
+            launch_config: Mutex::new(launch_config),
+            ipc_endpoint: Mutex::new(None),
+            launch_seq: AtomicU32::new(0),
             parent_hwnd: Mutex::new(None),
             starting: Mutex::new(false),
+            log_buffer: Arc::new(Mutex::new(VecDeque::with_capacity(LOG_BUFFER_LINES))),
+            restart_policy: Mutex::new(None),
+            restart_attempts: AtomicU32::new(0),
+            restart_hook: Mutex::new(None),
+        })
+    }
+
+    /// Register the callback invoked with the new `IpcEndpoint` each time a
+    /// supervised auto-restart relaunches the engine, so the caller can
+    /// re-point the paired `IpcBridge` at it. Overwrites any previously set
+    /// hook - there's only ever one bridge paired with a given manager.
+    pub fn set_restart_hook(&self, hook: impl Fn(IpcEndpoint) + Send + Sync + 'static) {
+        *self.restart_hook.lock().unwrap() = Some(Box::new(hook));
+    }
+
+    /// Enable supervised auto-restart: an unexpected engine exit relaunches
+    /// the engine with the given backoff policy instead of staying dead.
+    pub fn enable_supervisor(&self, policy: RestartPolicy) {
+        *self.restart_policy.lock().unwrap() = Some(policy);
+        self.restart_attempts.store(0, Ordering::SeqCst);
+    }
+
+    pub fn disable_supervisor(&self) {
+        *self.restart_policy.lock().unwrap() = None;
+    }
+
+    /// Called from the reaper thread when the engine exits without having
+    /// been asked to stop. Schedules a backed-off relaunch if supervision is
+    /// enabled and attempts remain.
+    fn on_unexpected_exit(self: &Arc<Self>) {
+        let policy = match *self.restart_policy.lock().unwrap() {
+            Some(policy) => policy,
+            None => return,
+        };
+
+        let attempt = self.restart_attempts.fetch_add(1, Ordering::SeqCst) + 1;
+        if attempt > policy.max_attempts {
+            println!(
+                "Engine supervisor: giving up after {} restart attempts",
+                policy.max_attempts
+            );
+            return;
         }
+
+        let backoff = policy
+            .initial_backoff
+            .saturating_mul(1 << (attempt - 1).min(16))
+            .min(policy.max_backoff);
+
+        println!(
+            "Engine supervisor: restarting in {:?} (attempt {}/{})",
+            backoff, attempt, policy.max_attempts
+        );
+
+        let manager = self.clone();
+        std::thread::spawn(move || {
+            std::thread::sleep(backoff);
+            match manager.start() {
+                Ok(true) => {
+                    manager.finish_starting();
+                    if let Some(endpoint) = manager.ipc_endpoint() {
+                        if let Some(hook) = manager.restart_hook.lock().unwrap().as_ref() {
+                            hook(endpoint);
+                        }
+                    }
+                    manager.watch_for_stability(policy);
+                }
+                Ok(false) => {
+                    // Someone else started it in the meantime (or it's still starting).
+                }
+                Err(e) => println!("Engine supervisor: restart failed: {}", e),
+            }
+        });
+    }
+
+    /// After a supervised restart, reset the attempt counter once the engine
+    /// has stayed alive past the policy's stability threshold.
+    fn watch_for_stability(self: &Arc<Self>, policy: RestartPolicy) {
+        let manager = self.clone();
+        std::thread::spawn(move || {
+            std::thread::sleep(policy.stability_threshold);
+            if manager.is_running() {
+                manager.restart_attempts.store(0, Ordering::SeqCst);
+                println!("Engine supervisor: engine stable, restart counter reset");
+            }
+        });
     }
 
     pub fn set_parent_hwnd(&self, hwnd: u64) {
@@ -49,20 +498,34 @@ impl EngineManager {
         if let Ok(mut starting) = self.starting.lock() {
             if *starting {
                 println!("Engine already starting, skipping");
-                return Ok(false);  // Skipped - already starting
+                return Ok(false); // Skipped - already starting
             }
             *starting = true;
         }
 
         let mut process_guard = self.process.lock().map_err(|e| e.to_string())?;
 
-        if process_guard.is_some() {
-            // Already running - this is fine (React StrictMode calls twice in dev)
-            println!("Engine already running, skipping start");
-            if let Ok(mut starting) = self.starting.lock() {
-                *starting = false;
+        match process_guard.take() {
+            Some(running) if running.state.running.load(Ordering::SeqCst) => {
+                // Already running - this is fine (React StrictMode calls twice in dev)
+                println!("Engine already running, skipping start");
+                *process_guard = Some(running);
+                if let Ok(mut starting) = self.starting.lock() {
+                    *starting = false;
+                }
+                return Ok(false); // Skipped - already running
             }
-            return Ok(false);  // Skipped - already running
+            Some(stale) => {
+                // The reaper already marked this dead (an unexpected exit,
+                // possibly one `on_unexpected_exit` is about to retry) but
+                // left the entry behind - without clearing it, every
+                // relaunch attempt would see a stale `Some` here and skip
+                // starting forever, silently turning the supervisor's
+                // restart into a no-op.
+                println!("Clearing stale engine process entry before restart");
+                teardown(stale, false);
+            }
+            None => {}
         }
 
         // Check if engine exists
@@ -71,40 +534,108 @@ impl EngineManager {
         }
 
         // Get the engine's working directory (where shaders are)
-        let working_dir = self
-            .engine_path
-            .parent()
-            .ok_or("Invalid engine path")?;
+        let working_dir = self.engine_path.parent().ok_or("Invalid engine path")?;
 
         println!("Starting engine: {:?}", self.engine_path);
         println!("Working dir: {:?}", working_dir);
 
-        // Build command arguments
-        let mut args = vec![
-            "--ipc-port".to_string(),
-            self.ipc_port.to_string(),
-            "--editor-mode".to_string(),
-            "--no-vulkan".to_string(),  // TEMP: Test window embedding without Vulkan
-        ];
+        let config = self
+            .launch_config
+            .lock()
+            .map_err(|e| e.to_string())?
+            .clone();
+
+        let endpoint = match config.transport {
+            TransportSelection::Tcp(port_selection) => {
+                let resolved_port = match port_selection {
+                    PortSelection::Fixed(port) => port,
+                    PortSelection::Auto => {
+                        probe_free_port().ok_or("Failed to find a free IPC port")?
+                    }
+                };
+                IpcEndpoint::Tcp(resolved_port)
+            }
+            TransportSelection::Native => {
+                let seq = self.launch_seq.fetch_add(1, Ordering::SeqCst);
+                IpcEndpoint::for_launch(&format!("{}_{}", std::process::id(), seq))
+            }
+        };
+        *self.ipc_endpoint.lock().map_err(|e| e.to_string())? = Some(endpoint.clone());
+
+        // Build command arguments from the launch config
+        let mut args = match &endpoint {
+            IpcEndpoint::Tcp(port) => vec!["--ipc-port".to_string(), port.to_string()],
+            #[cfg(unix)]
+            IpcEndpoint::UnixSocket(path) => {
+                vec!["--ipc-socket".to_string(), path.display().to_string()]
+            }
+            #[cfg(windows)]
+            IpcEndpoint::NamedPipe(name) => vec!["--ipc-pipe".to_string(), name.clone()],
+        };
+        if config.editor_mode {
+            args.push("--editor-mode".to_string());
+        }
+        if config.no_vulkan {
+            args.push("--no-vulkan".to_string());
+        }
+        args.extend(config.extra_args.iter().cloned());
 
-        // Add parent HWND if set (for window embedding)
+        // Add the parent window handle if set, translated into this
+        // platform's launch args by the backend (for window embedding)
         if let Ok(hwnd_guard) = self.parent_hwnd.lock() {
-            if let Some(hwnd) = *hwnd_guard {
-                args.push("--parent-hwnd".to_string());
-                args.push(hwnd.to_string());
-                println!("Embedding in parent HWND: {}", hwnd);
+            if let Some(window_handle) = *hwnd_guard {
+                args.extend(self.backend.embed_args(window_handle));
+                println!("Embedding in parent window handle: {}", window_handle);
             }
         }
 
-        let child = Command::new(&self.engine_path)
+        let mut child = Command::new(&self.engine_path)
             .current_dir(working_dir)
             .args(&args)
+            .envs(config.env.iter().cloned())
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
             .spawn()
             .map_err(|e| format!("Failed to start engine at {:?}: {}", self.engine_path, e))?;
 
         println!("Engine started with PID: {}", child.id());
-        *process_guard = Some(child);
-        Ok(true)  // Actually started
+
+        // Take the pipe handles before the child is moved into the mutex, so
+        // the forwarder threads own them independently of the process guard.
+        let stdin: ChildStdin = child.stdin.take().ok_or("Engine stdin was not piped")?;
+        let stdout: ChildStdout = child.stdout.take().ok_or("Engine stdout was not piped")?;
+        let stderr: ChildStderr = child.stderr.take().ok_or("Engine stderr was not piped")?;
+
+        let stdout_forwarder =
+            LogForwarder::spawn(stdout, "stdout", self.app.clone(), self.log_buffer.clone());
+        let stderr_forwarder =
+            LogForwarder::spawn(stderr, "stderr", self.app.clone(), self.log_buffer.clone());
+
+        let child = Arc::new(Mutex::new(child));
+        let state = Arc::new(ProcessState {
+            running: AtomicBool::new(true),
+            exit_status: Mutex::new(None),
+        });
+        let expected_stop = Arc::new(AtomicBool::new(false));
+        let reaper = ReaperThread::spawn(
+            child.clone(),
+            state.clone(),
+            expected_stop.clone(),
+            self.app.clone(),
+            self.self_ref.clone(),
+        );
+
+        *process_guard = Some(RunningEngine {
+            child,
+            stdin: Mutex::new(Some(stdin)),
+            stdout_forwarder,
+            stderr_forwarder,
+            reaper,
+            state,
+            expected_stop,
+        });
+        Ok(true) // Actually started
     }
 
     pub fn finish_starting(&self) {
@@ -113,7 +644,15 @@ impl EngineManager {
         }
     }
 
-    /// Stop the engine. If force=true, stops even during startup (for window close).
+    /// Returns the last lines captured from the engine's stdout/stderr, oldest first.
+    pub fn recent_logs(&self) -> Vec<String> {
+        self.log_buffer
+            .lock()
+            .map(|buffer| buffer.iter().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    /// Stop the engine immediately. If force=true, stops even during startup (for window close).
     pub fn stop_with_force(&self, force: bool) -> Result<(), String> {
         // Don't stop if we're in the middle of starting (React StrictMode issue)
         // Unless force is true (for window close / cleanup)
@@ -135,10 +674,9 @@ impl EngineManager {
 
         let mut process_guard = self.process.lock().map_err(|e| e.to_string())?;
 
-        if let Some(mut child) = process_guard.take() {
-            println!("Stopping engine process (PID: {})", child.id());
-            let _ = child.kill();
-            let _ = child.wait();
+        if let Some(running) = process_guard.take() {
+            println!("Force-stopping engine process");
+            teardown(running, true);
             println!("Engine process stopped");
         }
 
@@ -149,32 +687,80 @@ impl EngineManager {
         self.stop_with_force(false)
     }
 
+    /// Ask the engine to shut down cleanly (by closing its stdin) and wait up
+    /// to `timeout` for it to exit on its own before escalating to `kill()`.
+    pub fn stop_graceful(&self, timeout: Duration) -> Result<(), String> {
+        {
+            // Signal intent to stop and close stdin so the engine can flush
+            // state and release the embedded window before we poll for exit.
+            let process_guard = self.process.lock().map_err(|e| e.to_string())?;
+            let running = match process_guard.as_ref() {
+                Some(running) => running,
+                None => return Ok(()),
+            };
+            running.expected_stop.store(true, Ordering::SeqCst);
+            if let Ok(mut stdin_guard) = running.stdin.lock() {
+                *stdin_guard = None; // dropping closes the pipe
+            }
+        }
+
+        let deadline = Instant::now() + timeout;
+        while self.is_running() && Instant::now() < deadline {
+            std::thread::sleep(Duration::from_millis(50));
+        }
+
+        let mut process_guard = self.process.lock().map_err(|e| e.to_string())?;
+        if let Some(running) = process_guard.take() {
+            let exited_on_its_own = !running.state.running.load(Ordering::SeqCst);
+            if exited_on_its_own {
+                println!("Engine exited gracefully");
+            } else {
+                println!("Engine did not exit within {:?}, forcing kill", timeout);
+            }
+            teardown(running, !exited_on_its_own);
+        }
+
+        Ok(())
+    }
+
+    /// Cheap, lock-free-ish liveness check backed by the reaper thread instead
+    /// of shelling out to `tasklist` on every call.
     pub fn is_running(&self) -> bool {
         let process_guard = match self.process.lock() {
             Ok(guard) => guard,
             Err(_) => return false,
         };
 
-        if let Some(ref child) = *process_guard {
-            // Check if process is still running by trying to get exit status
-            // This is a non-blocking check
-            match std::process::Command::new("tasklist")
-                .args(["/FI", &format!("PID eq {}", child.id())])
-                .output()
-            {
-                Ok(output) => {
-                    let output_str = String::from_utf8_lossy(&output.stdout);
-                    output_str.contains(&child.id().to_string())
-                }
-                Err(_) => false,
-            }
-        } else {
-            false
+        match process_guard.as_ref() {
+            Some(running) => running.state.running.load(Ordering::SeqCst),
+            None => false,
         }
     }
 
-    pub fn ipc_port(&self) -> u16 {
-        self.ipc_port
+    /// The exit status of the current engine process entry, if it has
+    /// terminated. `None` once that entry has been stopped or replaced by a
+    /// new `start()`.
+    pub fn last_exit_status(&self) -> Option<ExitStatus> {
+        let process_guard = self.process.lock().ok()?;
+        let running = process_guard.as_ref()?;
+        *running.state.exit_status.lock().ok()?
+    }
+
+    /// The IPC endpoint the engine is (or will be) listening on. Resolved
+    /// from the launch config's transport selection the last time `start()`
+    /// ran; `None` until the engine has been started at least once.
+    pub fn ipc_endpoint(&self) -> Option<IpcEndpoint> {
+        self.ipc_endpoint
+            .lock()
+            .ok()
+            .and_then(|guard| guard.clone())
+    }
+
+    /// Replace the launch config used by subsequent `start()` calls.
+    pub fn set_launch_config(&self, config: LaunchConfig) {
+        if let Ok(mut guard) = self.launch_config.lock() {
+            *guard = config;
+        }
     }
 
     pub fn set_engine_path(&mut self, path: PathBuf) {
@@ -182,15 +768,27 @@ impl EngineManager {
     }
 }
 
-impl Drop for EngineManager {
-    fn drop(&mut self) {
-        // Force stop on drop (editor closing)
-        let _ = self.stop_with_force(true);
+/// Tears down a `RunningEngine`: optionally kills it, then joins every
+/// background thread so they don't leak across restarts or process exit.
+fn teardown(mut running: RunningEngine, kill: bool) {
+    running.expected_stop.store(true, Ordering::SeqCst);
+
+    if let Ok(mut child) = running.child.lock() {
+        if kill {
+            let _ = child.kill();
+        }
+        let _ = child.wait();
     }
+
+    running.reaper.join();
+    running.stdout_forwarder.join();
+    running.stderr_forwarder.join();
 }
 
-impl Default for EngineManager {
-    fn default() -> Self {
-        Self::new()
+impl Drop for EngineManager {
+    fn drop(&mut self) {
+        // Give the engine a short grace window to shut down cleanly before
+        // falling back to a hard kill, so editor close still terminates it.
+        let _ = self.stop_graceful(DROP_GRACE_PERIOD);
     }
 }
@@ -1,10 +1,178 @@
 use futures_util::{SinkExt, StreamExt};
 use serde::{Deserialize, Serialize};
 use serde_json::{json, Value};
-use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::collections::HashMap;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, AtomicU16, AtomicU64, Ordering};
 use std::sync::Arc;
-use tokio::sync::Mutex;
-use tokio_tungstenite::{connect_async, tungstenite::Message};
+use std::time::Duration;
+use tokio::io::{AsyncRead, AsyncWrite};
+use tokio::sync::{mpsc, oneshot, Mutex};
+use tokio::task::JoinHandle;
+use tokio_tungstenite::tungstenite::Message;
+
+/// Where to reach the engine's IPC server. A Unix domain socket or Windows
+/// named pipe is preferred - a loopback TCP port is reachable by any local
+/// process and races with other instances on reconnect - so `Tcp` is kept
+/// only as an explicit fallback for configurations that ask for it.
+#[derive(Debug, Clone)]
+pub enum IpcEndpoint {
+    #[cfg(unix)]
+    UnixSocket(std::path::PathBuf),
+    #[cfg(windows)]
+    NamedPipe(String),
+    Tcp(u16),
+}
+
+impl IpcEndpoint {
+    /// The platform-appropriate non-TCP endpoint for an engine launch,
+    /// namespaced by `launch_id` (the editor's own pid plus a per-launch
+    /// sequence number - the engine's own pid isn't known until after it's
+    /// spawned, but the socket path/pipe name has to be in its argv) so
+    /// sibling instances in the pool don't collide.
+    pub fn for_launch(launch_id: &str) -> Self {
+        #[cfg(unix)]
+        {
+            IpcEndpoint::UnixSocket(
+                std::env::temp_dir().join(format!("ascii_dungeon_{}.sock", launch_id)),
+            )
+        }
+
+        #[cfg(windows)]
+        {
+            IpcEndpoint::NamedPipe(format!(r"\\.\pipe\ascii_dungeon_{}", launch_id))
+        }
+    }
+}
+
+/// How many times to retry a Windows named pipe connect while the server
+/// reports `ERROR_PIPE_BUSY` (all its pipe instances are momentarily taken).
+#[cfg(windows)]
+const PIPE_BUSY_RETRIES: u32 = 20;
+#[cfg(windows)]
+const PIPE_BUSY_RETRY_DELAY: std::time::Duration = std::time::Duration::from_millis(50);
+
+/// Thin wrapper around `NamedPipeClient` so the rest of the bridge can treat
+/// a connected named pipe exactly like any other `AsyncRead + AsyncWrite`
+/// transport, the same as a Unix socket or TCP stream.
+#[cfg(windows)]
+struct NamedPipeStream(tokio::net::windows::named_pipe::NamedPipeClient);
+
+#[cfg(windows)]
+impl AsyncRead for NamedPipeStream {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+        buf: &mut tokio::io::ReadBuf<'_>,
+    ) -> std::task::Poll<std::io::Result<()>> {
+        Pin::new(&mut self.get_mut().0).poll_read(cx, buf)
+    }
+}
+
+#[cfg(windows)]
+impl AsyncWrite for NamedPipeStream {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+        buf: &[u8],
+    ) -> std::task::Poll<std::io::Result<usize>> {
+        Pin::new(&mut self.get_mut().0).poll_write(cx, buf)
+    }
+
+    fn poll_flush(
+        self: Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<std::io::Result<()>> {
+        Pin::new(&mut self.get_mut().0).poll_flush(cx)
+    }
+
+    fn poll_shutdown(
+        self: Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<std::io::Result<()>> {
+        Pin::new(&mut self.get_mut().0).poll_shutdown(cx)
+    }
+}
+
+/// Connect to a Windows named pipe, retrying while the server reports
+/// `ERROR_PIPE_BUSY` (it's still finishing up a previous client) instead of
+/// failing on the first attempt.
+#[cfg(windows)]
+async fn connect_named_pipe(name: &str) -> std::io::Result<NamedPipeStream> {
+    use tokio::net::windows::named_pipe::ClientOptions;
+
+    for attempt in 0..PIPE_BUSY_RETRIES {
+        match ClientOptions::new().open(name) {
+            Ok(client) => return Ok(NamedPipeStream(client)),
+            Err(e) if e.raw_os_error() == Some(231 /* ERROR_PIPE_BUSY */) => {
+                if attempt + 1 == PIPE_BUSY_RETRIES {
+                    return Err(e);
+                }
+                tokio::time::sleep(PIPE_BUSY_RETRY_DELAY).await;
+            }
+            Err(e) => return Err(e),
+        }
+    }
+
+    unreachable!("loop always returns")
+}
+
+/// A transport stream boxed behind a single type so `WebSocketStream` (and
+/// thus `WsWriter`/`WsReader`) don't need a type parameter per transport -
+/// Unix socket, named pipe, and TCP all look the same from here on.
+type BoxedStream = Pin<Box<dyn AsyncRead + AsyncWrite + Send + Unpin>>;
+
+async fn connect_transport(endpoint: &IpcEndpoint) -> Result<BoxedStream, String> {
+    match endpoint {
+        #[cfg(unix)]
+        IpcEndpoint::UnixSocket(path) => {
+            let stream = tokio::net::UnixStream::connect(path)
+                .await
+                .map_err(|e| format!("Failed to connect to {:?}: {}", path, e))?;
+            Ok(Box::pin(stream))
+        }
+        #[cfg(windows)]
+        IpcEndpoint::NamedPipe(name) => {
+            let stream = connect_named_pipe(name)
+                .await
+                .map_err(|e| format!("Failed to connect to {}: {}", name, e))?;
+            Ok(Box::pin(stream))
+        }
+        IpcEndpoint::Tcp(port) => {
+            let stream = tokio::net::TcpStream::connect(("127.0.0.1", *port))
+                .await
+                .map_err(|e| format!("Failed to connect to 127.0.0.1:{}: {}", port, e))?;
+            Ok(Box::pin(stream))
+        }
+    }
+}
+
+/// Connection lifecycle, exposed so the UI can show "reconnecting..." rather
+/// than a hard failure when the engine restarts or the page reloads.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ConnectionState {
+    Connected,
+    Reconnecting,
+    Disconnected,
+}
+
+/// Backoff schedule for `reconnect`: doubles each attempt, capped at
+/// `RECONNECT_MAX_BACKOFF`, with up to 50% jitter so several pooled engine
+/// instances restarting together don't all retry in lockstep.
+const RECONNECT_INITIAL_BACKOFF: Duration = Duration::from_millis(100);
+const RECONNECT_MAX_BACKOFF: Duration = Duration::from_secs(5);
+
+/// Cheap, dependency-free jitter: the low bits of the current time, with no
+/// need for a `rand` crate for something this low-stakes.
+fn jitter(backoff: Duration) -> Duration {
+    let nanos = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.subsec_nanos())
+        .unwrap_or(0);
+    let jitter_fraction = (nanos % 500) as f64 / 1000.0; // up to 50%
+    backoff + Duration::from_secs_f64(backoff.as_secs_f64() * jitter_fraction)
+}
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct IpcRequest {
@@ -27,73 +195,342 @@ pub struct IpcResponse {
     pub error: Option<String>,
 }
 
-type WsWriter = futures_util::stream::SplitSink<
-    tokio_tungstenite::WebSocketStream<
-        tokio_tungstenite::MaybeTlsStream<tokio::net::TcpStream>,
-    >,
-    Message,
->;
+type WsWriter =
+    futures_util::stream::SplitSink<tokio_tungstenite::WebSocketStream<BoxedStream>, Message>;
+
+type WsReader = futures_util::stream::SplitStream<tokio_tungstenite::WebSocketStream<BoxedStream>>;
 
-type WsReader = futures_util::stream::SplitStream<
-    tokio_tungstenite::WebSocketStream<
-        tokio_tungstenite::MaybeTlsStream<tokio::net::TcpStream>,
-    >,
->;
+/// A response frame whose id didn't match anything in the pending map or the
+/// subscriptions map - not a reply to a `send_command` call, and not an event
+/// for any channel we're still listening on. Just logged so nothing is
+/// silently dropped.
+fn handle_unmatched_frame(response: IpcResponse) {
+    println!(
+        "IPC: unmatched frame (no pending request or subscription for id {}): {:?}",
+        response.id, response
+    );
+}
+
+/// One live `subscribe()` call: the method/params used to establish it (so
+/// `reconnect()` can re-issue it against a fresh connection) plus the
+/// engine-assigned id that tags the event frames it should receive. The id
+/// is re-assigned by `resubscribe_all` after a reconnect, since the engine
+/// has no memory of the old connection's subscriptions.
+struct Subscription {
+    method: String,
+    params: Value,
+    engine_id: String,
+    sender: mpsc::UnboundedSender<IpcResponse>,
+}
 
 pub struct IpcBridge {
     writer: Arc<Mutex<Option<WsWriter>>>,
-    reader: Arc<Mutex<Option<WsReader>>>,
     request_id: AtomicU64,
-    port: u16,
-    connected: AtomicBool,  // Track connection state to prevent double-connect
+    /// The TCP port to fall back to when `endpoint` is `None`. An `AtomicU16`
+    /// rather than a plain `u16` since a pooled engine instance's port is
+    /// only known once its process has started and resolved its auto-selected
+    /// port - `set_port` updates it in place instead of requiring a new
+    /// `IpcBridge`.
+    port: AtomicU16,
+    /// The endpoint to connect to, set by `set_endpoint` once the paired
+    /// `EngineManager` has resolved one. Takes priority over `port` - `port`
+    /// only matters if this is never set (an `IpcEndpoint::Tcp` carries its
+    /// own port).
+    endpoint: Mutex<Option<IpcEndpoint>>,
+    connected: AtomicBool, // Whether a connection is live; `reconnect()` skips its work if already set
+    /// Lifecycle exposed to callers via `connection_state()`.
+    state: Mutex<ConnectionState>,
+    /// Set by `disconnect()` and cleared by `connect()`, so a background
+    /// read task ending because the caller asked to disconnect doesn't spin
+    /// up a reconnect loop that nothing is waiting on anymore.
+    shutting_down: AtomicBool,
+    /// Requests dispatched but not yet answered, keyed by request id. Holds
+    /// both the original request (re-sent on reconnect) and the `oneshot`
+    /// that completes `send_command`'s `.await` once the background read
+    /// task matches a response to this id - no reader lock held while
+    /// waiting, so concurrent `send_command` calls don't block each other.
+    pending: Mutex<HashMap<String, (IpcRequest, oneshot::Sender<IpcResponse>)>>,
+    /// The background read task owning the current `WsReader`, so a fresh
+    /// `connect()`/`reconnect()` can abort the previous one instead of
+    /// leaking it.
+    reader_task: Mutex<Option<JoinHandle<()>>>,
+    /// Held for the duration of `reconnect()` so a read-loop-triggered
+    /// reconnect and a failed-`send_command`-triggered reconnect can't race
+    /// each other into `establish()` at the same time - the second caller
+    /// waits out the first's attempt, then finds the bridge already
+    /// connected and returns without redoing the work.
+    reconnect_lock: Mutex<()>,
+    /// Live event subscriptions, keyed by the handle returned from
+    /// `subscribe()` (stable across reconnects, unlike the engine-assigned
+    /// id inside each `Subscription`). `read_loop` scans these for a frame
+    /// whose id doesn't match a pending request, and `reconnect()` re-issues
+    /// every one of them against the new connection.
+    subscriptions: Mutex<HashMap<String, Subscription>>,
 }
 
 impl IpcBridge {
     pub fn new() -> Self {
         Self {
             writer: Arc::new(Mutex::new(None)),
-            reader: Arc::new(Mutex::new(None)),
             request_id: AtomicU64::new(1),
-            port: 9999,
+            port: AtomicU16::new(9999),
+            endpoint: Mutex::new(None),
             connected: AtomicBool::new(false),
+            state: Mutex::new(ConnectionState::Disconnected),
+            shutting_down: AtomicBool::new(false),
+            pending: Mutex::new(HashMap::new()),
+            reader_task: Mutex::new(None),
+            reconnect_lock: Mutex::new(()),
+            subscriptions: Mutex::new(HashMap::new()),
         }
     }
 
-    pub async fn connect(&self) -> Result<(), String> {
+    /// Current connection lifecycle state, for the UI to surface.
+    pub async fn connection_state(&self) -> ConnectionState {
+        *self.state.lock().await
+    }
+
+    /// Point this bridge at a different TCP port, used only while no endpoint
+    /// has been set with `set_endpoint`. Takes effect on the next `connect()`.
+    pub fn set_port(&self, port: u16) {
+        self.port.store(port, Ordering::SeqCst);
+    }
+
+    /// Point this bridge at the engine's resolved IPC endpoint - a Unix
+    /// socket path or Windows named pipe name, or `IpcEndpoint::Tcp` if the
+    /// engine was explicitly configured to fall back to TCP. Takes effect on
+    /// the next `connect()`.
+    pub async fn set_endpoint(&self, endpoint: IpcEndpoint) {
+        *self.endpoint.lock().await = Some(endpoint);
+    }
+
+    pub async fn connect(self: &Arc<Self>) -> Result<(), String> {
+        self.shutting_down.store(false, Ordering::SeqCst);
+
         // Always try to reconnect - old connections may be stale after page reload
-        // First, clean up any existing connection
         if self.connected.load(Ordering::SeqCst) {
             println!("IPC was connected, resetting for fresh connection");
-            *self.writer.lock().await = None;
-            *self.reader.lock().await = None;
             self.connected.store(false, Ordering::SeqCst);
         }
 
-        let url = format!("ws://127.0.0.1:{}", self.port);
-        let (ws_stream, _) = connect_async(&url)
+        self.establish().await?;
+        *self.state.lock().await = ConnectionState::Connected;
+        Ok(())
+    }
+
+    /// The actual transport + WebSocket handshake, shared by `connect()` and
+    /// `reconnect()`: connects, then spawns the background read task that
+    /// owns the reader for the rest of this connection's lifetime. Does not
+    /// touch `state` - callers decide what that means for their situation.
+    async fn establish(self: &Arc<Self>) -> Result<(), String> {
+        let endpoint = self
+            .endpoint
+            .lock()
+            .await
+            .clone()
+            .unwrap_or(IpcEndpoint::Tcp(self.port.load(Ordering::SeqCst)));
+
+        let stream = connect_transport(&endpoint).await?;
+        let (ws_stream, _) = tokio_tungstenite::client_async("ws://ipc.local/", stream)
             .await
             .map_err(|e| format!("Failed to connect: {}", e))?;
 
         let (writer, reader) = ws_stream.split();
 
         *self.writer.lock().await = Some(writer);
-        *self.reader.lock().await = Some(reader);
         self.connected.store(true, Ordering::SeqCst);
 
-        println!("IPC connected to {}", url);
+        if let Some(old_task) = self.reader_task.lock().await.take() {
+            old_task.abort();
+        }
+        let bridge = self.clone();
+        let handle = tokio::spawn(async move { bridge.read_loop(reader).await });
+        *self.reader_task.lock().await = Some(handle);
+
+        println!("IPC connected to {:?}", endpoint);
         Ok(())
     }
 
-    pub async fn disconnect(&self) {
-        // Only disconnect if we're actually connected
-        if !self.connected.load(Ordering::SeqCst) {
-            println!("IPC not connected, skipping disconnect");
-            return;
+    /// Owns the `WsReader` for one connection's lifetime: demultiplexes
+    /// incoming frames to whichever `send_command` call is waiting on that
+    /// id, routing anything unmatched to the event path instead of dropping
+    /// it. Spawns a reconnect once the stream ends (unless the bridge was
+    /// explicitly asked to disconnect in the meantime) rather than awaiting
+    /// it inline - `establish()` aborts the previous `reader_task` to avoid
+    /// leaking it, and that handle is this very task while it's still
+    /// running here, so awaiting `reconnect()` in place would have it abort
+    /// itself mid-flight the moment it yields past that point.
+    async fn read_loop(self: Arc<Self>, mut reader: WsReader) {
+        loop {
+            match reader.next().await {
+                Some(Ok(Message::Text(text))) => match serde_json::from_str::<IpcResponse>(&text) {
+                    Ok(response) => {
+                        let waiter = self.pending.lock().await.remove(&response.id);
+                        match waiter {
+                            Some((_, tx)) => {
+                                let _ = tx.send(response);
+                            }
+                            None => self.dispatch_event(response).await,
+                        }
+                    }
+                    Err(e) => println!("IPC: failed to parse frame: {}", e),
+                },
+                Some(Ok(Message::Close(_))) => break,
+                Some(Err(e)) => {
+                    println!("IPC read error: {}", e);
+                    break;
+                }
+                None => break,
+                _ => {}
+            }
+        }
+
+        self.connected.store(false, Ordering::SeqCst);
+        if !self.shutting_down.load(Ordering::SeqCst) {
+            let bridge = self.clone();
+            tokio::spawn(async move {
+                let _ = bridge.reconnect().await;
+            });
+        }
+    }
+
+    /// Route a frame that didn't match any pending request to the
+    /// subscription it belongs to, if any - an unsolicited push from the
+    /// engine is only ever an event for something `subscribe()` registered.
+    async fn dispatch_event(&self, response: IpcResponse) {
+        let subscriptions = self.subscriptions.lock().await;
+        match subscriptions
+            .values()
+            .find(|sub| sub.engine_id == response.id)
+        {
+            Some(sub) => {
+                let _ = sub.sender.send(response);
+            }
+            None => {
+                drop(subscriptions);
+                handle_unmatched_frame(response);
+            }
+        }
+    }
+
+    /// Reconnect with exponential backoff after a transport error, then
+    /// re-send every still-pending request over the new writer so each
+    /// caller's `send_command` eventually resolves instead of failing.
+    /// Retries indefinitely (unless `disconnect()` is called meanwhile) -
+    /// the engine is expected to come back eventually, and giving up would
+    /// strand every in-flight caller anyway.
+    ///
+    /// Takes `reconnect_lock` for its whole body so a read-loop-triggered
+    /// reconnect and a failed-`send_command`-triggered reconnect can't both
+    /// call `establish()` at once. The second caller through the lock
+    /// re-checks `connected` first - the first caller already did the work
+    /// while it waited, so it returns immediately instead of reconnecting
+    /// a second time.
+    async fn reconnect(self: &Arc<Self>) -> Result<(), String> {
+        let _guard = self.reconnect_lock.lock().await;
+        if self.connected.load(Ordering::SeqCst) {
+            return Ok(());
+        }
+
+        *self.state.lock().await = ConnectionState::Reconnecting;
+        *self.writer.lock().await = None;
+        self.connected.store(false, Ordering::SeqCst);
+
+        let mut backoff = RECONNECT_INITIAL_BACKOFF;
+        while !self.shutting_down.load(Ordering::SeqCst) {
+            match self.establish().await {
+                Ok(()) => break,
+                Err(e) => {
+                    println!("IPC reconnect failed, retrying in {:?}: {}", backoff, e);
+                    tokio::time::sleep(jitter(backoff)).await;
+                    backoff = (backoff * 2).min(RECONNECT_MAX_BACKOFF);
+                }
+            }
+        }
+        if self.shutting_down.load(Ordering::SeqCst) {
+            *self.state.lock().await = ConnectionState::Disconnected;
+            return Err("Disconnected while reconnecting".to_string());
+        }
+
+        let pending_requests: Vec<IpcRequest> = self
+            .pending
+            .lock()
+            .await
+            .values()
+            .map(|(request, _)| request.clone())
+            .collect();
+        if !pending_requests.is_empty() {
+            println!(
+                "IPC reconnected, re-sending {} pending request(s)",
+                pending_requests.len()
+            );
+            let mut writer_guard = self.writer.lock().await;
+            let writer = writer_guard.as_mut().ok_or("Not connected")?;
+            for request in pending_requests {
+                let request_json = serde_json::to_string(&request).map_err(|e| e.to_string())?;
+                writer
+                    .send(Message::Text(request_json))
+                    .await
+                    .map_err(|e| format!("Failed to re-send pending request: {}", e))?;
+            }
+        }
+
+        *self.state.lock().await = ConnectionState::Connected;
+
+        // Release the single-flight guard before resubscribing - if a
+        // subscribe send fails because the connection flaps again right
+        // away, `resubscribe_all` -> `send_command` -> `send_and_await`
+        // calls back into `reconnect()`, which would re-acquire this same
+        // lock on this same task and deadlock forever if still held here.
+        drop(_guard);
+        self.resubscribe_all().await;
+        Ok(())
+    }
+
+    /// Re-issue every live subscription's original `subscribe` request
+    /// against the freshly (re-)established connection, since the engine
+    /// has no memory of subscriptions made over a connection that's gone -
+    /// without this, a reconnect would silently stop delivering events to
+    /// channels callers are still holding a receiver for.
+    async fn resubscribe_all(self: &Arc<Self>) {
+        let to_resubscribe: Vec<(String, String, Value)> = self
+            .subscriptions
+            .lock()
+            .await
+            .iter()
+            .map(|(handle, sub)| (handle.clone(), sub.method.clone(), sub.params.clone()))
+            .collect();
+
+        for (handle, method, params) in to_resubscribe {
+            match self.request_subscription_id(&method, params).await {
+                Ok(engine_id) => {
+                    if let Some(sub) = self.subscriptions.lock().await.get_mut(&handle) {
+                        sub.engine_id = engine_id;
+                    }
+                }
+                Err(e) => {
+                    println!(
+                        "IPC: failed to re-subscribe '{}' after reconnect: {}",
+                        method, e
+                    );
+                }
+            }
         }
+    }
 
+    pub async fn disconnect(&self) {
+        // Idempotent - also used to stop an in-progress `reconnect()` loop,
+        // which leaves `connected` false even though there's still a task to
+        // tear down, so this can't just bail out on `!connected`.
+        self.shutting_down.store(true, Ordering::SeqCst);
+        if let Some(task) = self.reader_task.lock().await.take() {
+            task.abort();
+        }
         *self.writer.lock().await = None;
-        *self.reader.lock().await = None;
         self.connected.store(false, Ordering::SeqCst);
+        *self.state.lock().await = ConnectionState::Disconnected;
+        self.pending.lock().await.clear();
+        self.subscriptions.lock().await.clear();
         println!("IPC disconnected");
     }
 
@@ -102,7 +539,7 @@ impl IpcBridge {
     }
 
     pub async fn send_command(
-        &self,
+        self: &Arc<Self>,
         method: &str,
         params: Value,
     ) -> Result<Value, String> {
@@ -110,64 +547,111 @@ impl IpcBridge {
 
         let request = IpcRequest {
             msg_type: "request".to_string(),
-            id: id.clone(),
+            id,
             method: method.to_string(),
             params,
         };
 
-        let request_json =
-            serde_json::to_string(&request).map_err(|e| e.to_string())?;
+        self.send_and_await(request).await
+    }
 
-        // Send request
-        {
-            let mut writer_guard = self.writer.lock().await;
-            let writer = writer_guard
-                .as_mut()
-                .ok_or("Not connected")?;
+    /// Send `request` and wait for its matching response via a `oneshot`
+    /// completed by the background read task - no reader lock held here, so
+    /// concurrent `send_command` calls never block each other. Transparently
+    /// reconnects (letting `reconnect()` re-send this request) if the
+    /// transport drops out from under us.
+    async fn send_and_await(self: &Arc<Self>, request: IpcRequest) -> Result<Value, String> {
+        let (tx, rx) = oneshot::channel();
+        self.pending
+            .lock()
+            .await
+            .insert(request.id.clone(), (request.clone(), tx));
 
-            writer
-                .send(Message::Text(request_json))
-                .await
-                .map_err(|e| format!("Failed to send: {}", e))?;
+        let request_json = serde_json::to_string(&request).map_err(|e| e.to_string())?;
+        let send_result = {
+            let mut writer_guard = self.writer.lock().await;
+            match writer_guard.as_mut() {
+                Some(writer) => writer
+                    .send(Message::Text(request_json))
+                    .await
+                    .map_err(|e| format!("Failed to send: {}", e)),
+                None => Err("Not connected".to_string()),
+            }
+        };
+        // If the send failed the connection is already dead, so reconnect
+        // (which re-sends every pending request, this one included) instead
+        // of sending it a second time ourselves. Mark `connected` false
+        // before calling in, so `reconnect()`'s single-flight check can tell
+        // "someone else already reconnected while I waited for the lock"
+        // apart from "I'm the first one here".
+        if send_result.is_err() {
+            self.connected.store(false, Ordering::SeqCst);
+            self.reconnect().await?;
         }
 
-        // Read response
-        {
-            let mut reader_guard = self.reader.lock().await;
-            let reader = reader_guard
-                .as_mut()
-                .ok_or("Not connected")?;
-
-            // Wait for the response with matching ID
-            while let Some(msg) = reader.next().await {
-                match msg {
-                    Ok(Message::Text(text)) => {
-                        let response: IpcResponse =
-                            serde_json::from_str(&text).map_err(|e| e.to_string())?;
-
-                        if response.id == id {
-                            if response.success {
-                                return Ok(response.data.unwrap_or(json!({})));
-                            } else {
-                                return Err(response
-                                    .error
-                                    .unwrap_or_else(|| "Unknown error".to_string()));
-                            }
-                        }
-                        // If ID doesn't match, it might be an event - skip for now
-                    }
-                    Ok(Message::Close(_)) => {
-                        return Err("Connection closed".to_string());
-                    }
-                    Err(e) => {
-                        return Err(format!("WebSocket error: {}", e));
-                    }
-                    _ => {}
-                }
-            }
+        let response = rx
+            .await
+            .map_err(|_| "Connection closed before a response arrived".to_string())?;
+
+        if response.success {
+            Ok(response.data.unwrap_or(json!({})))
+        } else {
+            Err(response
+                .error
+                .unwrap_or_else(|| "Unknown error".to_string()))
         }
+    }
 
-        Err("No response received".to_string())
+    /// Subscribe to `method` (e.g. `"stats.subscribe"`), returning a handle
+    /// (stable across reconnects) and a channel that receives every event
+    /// frame the engine tags with the subscription id it hands back in this
+    /// call's response. Kept in `subscriptions` for the lifetime of the
+    /// channel so `reconnect()` can re-issue it and so `read_loop` knows
+    /// where to route events - callers should hold onto the handle and call
+    /// `unsubscribe()` once they're done, e.g. when a floating panel closes.
+    pub async fn subscribe(
+        self: &Arc<Self>,
+        method: &str,
+        params: Value,
+    ) -> Result<(String, mpsc::UnboundedReceiver<IpcResponse>), String> {
+        let engine_id = self.request_subscription_id(method, params.clone()).await?;
+
+        let handle = format!("sub-{}", self.request_id.fetch_add(1, Ordering::SeqCst));
+        let (tx, rx) = mpsc::unbounded_channel();
+        self.subscriptions.lock().await.insert(
+            handle.clone(),
+            Subscription {
+                method: method.to_string(),
+                params,
+                engine_id,
+                sender: tx,
+            },
+        );
+
+        Ok((handle, rx))
+    }
+
+    /// Drop a subscription by the handle `subscribe()` returned. Only local
+    /// bookkeeping - there's no engine-side "unsubscribe" method to call, so
+    /// the engine may keep pushing events for this id, but nothing is left
+    /// listening for them and `dispatch_event` logs them as unmatched.
+    pub async fn unsubscribe(&self, handle: &str) {
+        self.subscriptions.lock().await.remove(handle);
+    }
+
+    /// Send a `subscribe`-style request and pull the engine-assigned
+    /// subscription id out of its response, the shared step between
+    /// `subscribe()` and `resubscribe_all()`.
+    async fn request_subscription_id(
+        self: &Arc<Self>,
+        method: &str,
+        params: Value,
+    ) -> Result<String, String> {
+        let data = self.send_command(method, params).await?;
+        data.get("subscription_id")
+            .and_then(|v| v.as_str())
+            .map(|s| s.to_string())
+            .ok_or_else(|| format!("'{}' response did not include a subscription_id", method))
     }
 }
 
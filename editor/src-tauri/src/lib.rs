@@ -1,9 +1,12 @@
 mod commands;
 mod engine_process;
 mod ipc_bridge;
+mod platform;
+mod terminal;
 
 use tauri::Manager;
-use std::sync::{Arc, Mutex};
+use std::collections::HashMap;
+use std::sync::Mutex;
 
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
@@ -12,40 +15,55 @@ pub fn run() {
         .plugin(tauri_plugin_fs::init())
         .plugin(tauri_plugin_dialog::init())
         .setup(|app| {
-            // Initialize engine manager (wrapped in Arc for exit handler)
-            let engine_manager = Arc::new(engine_process::EngineManager::new());
-            app.manage(engine_manager);
-
-            // Initialize IPC bridge
-            let ipc_bridge = ipc_bridge::IpcBridge::new();
-            app.manage(ipc_bridge);
+            // Pool of engine instances keyed by instance id, each with its own
+            // EngineManager + IpcBridge pair so more than one can run at once.
+            let engine_pool = commands::EnginePool::new(app.handle().clone());
+            app.manage(engine_pool);
 
             // Initialize file watcher state
             let watcher_state = Mutex::new(commands::FileWatcherState::default());
             app.manage(watcher_state);
 
+            // Initialize asset hot-reload watcher state
+            let asset_watcher_state = Mutex::new(commands::AssetWatcherState::default());
+            app.manage(asset_watcher_state);
+
             // Initialize floating windows state
             let floating_state = Mutex::new(commands::FloatingWindowsState::default());
             app.manage(floating_state);
 
+            // Registry of in-flight Claude CLI processes, keyed by conversation
+            // id, so cancel_claude_message can find and terminate one.
+            let claude_processes: commands::ClaudeProcessRegistry = Mutex::new(HashMap::new());
+            app.manage(claude_processes);
+
+            // Registry of open, multi-turn Claude pty sessions, keyed by
+            // conversation id.
+            let claude_sessions: commands::ClaudeSessionRegistry = Mutex::new(HashMap::new());
+            app.manage(claude_sessions);
+
             Ok(())
         })
         .on_window_event(|window, event| {
             if let tauri::WindowEvent::Destroyed = event {
-                // Window is being destroyed - stop the engine
-                if let Some(engine) = window.app_handle().try_state::<Arc<engine_process::EngineManager>>() {
-                    println!("Window destroyed, stopping engine...");
-                    let _ = engine.stop_with_force(true);
+                // Window is being destroyed - stop only the engine instances
+                // it owns, so other windows' engines keep running.
+                if let Some(pool) = window.app_handle().try_state::<commands::EnginePool>() {
+                    println!("Window '{}' destroyed, stopping its engine instances...", window.label());
+                    pool.stop_window(window.label());
                 }
             }
         })
         .invoke_handler(tauri::generate_handler![
             commands::start_engine,
             commands::stop_engine,
+            commands::list_engine_instances,
             commands::get_stats,
             commands::get_camera,
             commands::set_camera,
             commands::send_command,
+            commands::subscribe_engine_events,
+            commands::unsubscribe_engine_events,
             commands::start_engine_embedded,
             commands::start_engine_with_parent,
             commands::resize_engine_viewport,
@@ -54,14 +72,23 @@ pub fn run() {
             commands::set_engine_follow,
             commands::show_engine,
             commands::get_window_hwnd,
+            commands::get_engine_logs,
+            commands::set_engine_supervised,
             // Claude Code integration
             commands::check_claude_available,
             commands::get_claude_path,
             commands::send_claude_message,
+            commands::send_claude_message_structured,
+            commands::cancel_claude_message,
+            commands::claude_session_open,
+            commands::claude_session_send,
+            commands::claude_session_close,
             commands::open_claude_auth,
             // File system watching
             commands::start_file_watcher,
             commands::stop_file_watcher,
+            commands::start_asset_watch,
+            commands::stop_asset_watch,
             // Palette / Prefab file system
             commands::scan_palette_folder,
             commands::read_prefab_file,
@@ -0,0 +1,76 @@
+use std::path::PathBuf;
+
+/// Per-OS knowledge the engine manager needs to launch and embed the engine.
+/// `EngineManager` holds one of these behind a `Box<dyn PlatformBackend>`
+/// selected via `cfg`, so `start`/`stop` stay platform-agnostic.
+pub trait PlatformBackend: Send + Sync {
+    /// Path to the compiled engine binary, relative to the repo root.
+    fn binary_relative_path(&self) -> PathBuf;
+
+    /// CLI args that tell the engine which native window to embed into,
+    /// translating this platform's window handle representation (HWND,
+    /// X11 window id / Wayland surface, or NSView pointer) into argv.
+    fn embed_args(&self, window_handle: u64) -> Vec<String>;
+}
+
+#[cfg(windows)]
+pub struct WindowsBackend;
+
+#[cfg(windows)]
+impl PlatformBackend for WindowsBackend {
+    fn binary_relative_path(&self) -> PathBuf {
+        PathBuf::from("build/Debug/ascii_dungeon.exe")
+    }
+
+    fn embed_args(&self, hwnd: u64) -> Vec<String> {
+        vec!["--parent-hwnd".to_string(), hwnd.to_string()]
+    }
+}
+
+#[cfg(target_os = "macos")]
+pub struct MacBackend;
+
+#[cfg(target_os = "macos")]
+impl PlatformBackend for MacBackend {
+    fn binary_relative_path(&self) -> PathBuf {
+        PathBuf::from("build/Debug/ascii_dungeon")
+    }
+
+    fn embed_args(&self, nsview_ptr: u64) -> Vec<String> {
+        vec!["--parent-nsview".to_string(), nsview_ptr.to_string()]
+    }
+}
+
+#[cfg(all(unix, not(target_os = "macos")))]
+pub struct LinuxBackend;
+
+#[cfg(all(unix, not(target_os = "macos")))]
+impl PlatformBackend for LinuxBackend {
+    fn binary_relative_path(&self) -> PathBuf {
+        PathBuf::from("build/Debug/ascii_dungeon")
+    }
+
+    fn embed_args(&self, window_id: u64) -> Vec<String> {
+        // Covers both an X11 window id and a Wayland surface handle - the
+        // engine picks the right windowing backend at runtime.
+        vec!["--parent-window".to_string(), window_id.to_string()]
+    }
+}
+
+/// Select the `PlatformBackend` for the OS this editor was compiled for.
+pub fn current() -> Box<dyn PlatformBackend> {
+    #[cfg(windows)]
+    {
+        Box::new(WindowsBackend)
+    }
+
+    #[cfg(target_os = "macos")]
+    {
+        Box::new(MacBackend)
+    }
+
+    #[cfg(all(unix, not(target_os = "macos")))]
+    {
+        Box::new(LinuxBackend)
+    }
+}
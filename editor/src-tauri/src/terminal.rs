@@ -0,0 +1,104 @@
+use serde::{Deserialize, Serialize};
+use std::process::{Child, Command};
+
+/// How to open a command in a new terminal window. Settings-backed so
+/// `open_claude_auth` (and any future "open this in a terminal" need) isn't
+/// locked to one hardcoded invocation per OS - a user on Alacritty, WezTerm,
+/// or Konsole can point this at their terminal instead.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "kind")]
+pub enum Shell {
+    /// Windows `cmd.exe /k`.
+    Cmd,
+    /// Windows PowerShell, kept open after the command with `-NoExit`.
+    PowerShell,
+    /// A Unix terminal emulator, named by binary (e.g. "gnome-terminal",
+    /// "konsole", "xterm"), invoked with its common `-- sh -c <cmd>` argv.
+    UnixShell(String),
+    /// A fully user-specified program plus an argument template containing
+    /// a `{cmd}` placeholder, for terminals whose invocation doesn't fit the
+    /// other variants (Alacritty, WezTerm, a custom wrapper script, ...).
+    Explicit {
+        program: String,
+        args_template: Vec<String>,
+    },
+}
+
+impl Shell {
+    /// Resolve this shell into a (program, argument template) pair. Every
+    /// variant reduces to this so `TerminalLauncher` only has one code path:
+    /// render `{cmd}` into the template and spawn.
+    fn resolve(&self) -> (String, Vec<String>) {
+        match self {
+            Shell::Cmd => (
+                "cmd".to_string(),
+                vec!["/c".into(), "start".into(), "cmd".into(), "/k".into(), "{cmd}".into()],
+            ),
+            Shell::PowerShell => (
+                "cmd".to_string(),
+                vec![
+                    "/c".into(),
+                    "start".into(),
+                    "powershell".into(),
+                    "-NoExit".into(),
+                    "-Command".into(),
+                    "{cmd}".into(),
+                ],
+            ),
+            Shell::UnixShell(program) => {
+                (program.clone(), vec!["--".into(), "sh".into(), "-c".into(), "{cmd}".into()])
+            }
+            Shell::Explicit { program, args_template } => (program.clone(), args_template.clone()),
+        }
+    }
+}
+
+/// The OS-appropriate default `Shell`, matching what `open_claude_auth` used
+/// to hardcode per platform before this abstraction existed.
+pub fn default_shell() -> Shell {
+    #[cfg(windows)]
+    {
+        Shell::Cmd
+    }
+
+    #[cfg(target_os = "macos")]
+    {
+        Shell::Explicit {
+            program: "osascript".to_string(),
+            args_template: vec![
+                "-e".to_string(),
+                "tell app \"Terminal\" to do script \"{cmd}\"".to_string(),
+            ],
+        }
+    }
+
+    #[cfg(all(unix, not(target_os = "macos")))]
+    {
+        Shell::UnixShell("gnome-terminal".to_string())
+    }
+}
+
+/// Launches a command line in a new terminal window per a configured `Shell`.
+pub struct TerminalLauncher {
+    shell: Shell,
+}
+
+impl TerminalLauncher {
+    pub fn new(shell: Shell) -> Self {
+        Self { shell }
+    }
+
+    /// Launch `command_line` in a new terminal window.
+    pub fn launch(&self, command_line: &str) -> Result<Child, String> {
+        let (program, args_template) = self.shell.resolve();
+        let args: Vec<String> = args_template
+            .iter()
+            .map(|arg| arg.replace("{cmd}", command_line))
+            .collect();
+
+        Command::new(&program)
+            .args(&args)
+            .spawn()
+            .map_err(|e| format!("Failed to open terminal '{}': {}", program, e))
+    }
+}